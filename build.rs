@@ -0,0 +1,41 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// Exposes the version of each locked dependency to the benchmark as
+// VERSION_<CRATE>, the compiler's version as VERSION_RUSTC, and the flags the
+// benchmark is compiled with as BUILD_TARGET, BUILD_RUSTFLAGS and
+// BUILD_TARGET_FEATURES.
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-env-changed=CARGO_ENCODED_RUSTFLAGS");
+
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
+    let lockfile = fs::read_to_string(Path::new(&manifest_dir).join("Cargo.lock")).unwrap();
+    let mut name = None;
+    for line in lockfile.lines() {
+        if let Some(value) = line.strip_prefix("name = ") {
+            name = Some(value.trim_matches('"'));
+        } else if let Some(value) = line.strip_prefix("version = ")
+            && let Some(name) = name.take()
+        {
+            let var = name.replace('-', "_").to_uppercase();
+            println!("cargo:rustc-env=VERSION_{var}={}", value.trim_matches('"'));
+        }
+    }
+
+    let rustc = env::var_os("RUSTC").unwrap();
+    let output = Command::new(rustc).arg("--version").output().unwrap();
+    let version = String::from_utf8(output.stdout).unwrap();
+    let version = version.split(' ').nth(1).unwrap();
+    println!("cargo:rustc-env=VERSION_RUSTC={version}");
+
+    let target = env::var("TARGET").unwrap();
+    println!("cargo:rustc-env=BUILD_TARGET={target}");
+    let rustflags = env::var("CARGO_ENCODED_RUSTFLAGS").unwrap_or_default();
+    let rustflags = rustflags.split('\x1f').collect::<Vec<_>>().join(" ");
+    println!("cargo:rustc-env=BUILD_RUSTFLAGS={rustflags}");
+    let features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    println!("cargo:rustc-env=BUILD_TARGET_FEATURES={features}");
+}
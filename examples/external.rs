@@ -0,0 +1,62 @@
+// A helper for --external that formats values using ryu, as a reference for
+// implementing the protocol described in src/external.rs in other languages.
+//
+//     cargo build --release --example external
+//     cargo run --release -- --external=ryu-external=target/release/examples/external
+
+use std::hint;
+use std::io::{self, BufRead as _, Write as _};
+use std::time::{Duration, Instant};
+
+fn main() {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines().map(Result::unwrap);
+    let mut stdout = io::stdout();
+
+    let header = lines.next().unwrap();
+    let mut header = header.split(' ');
+    let ty = header.next().unwrap().to_owned();
+    let trials: usize = header.next().unwrap().parse().unwrap();
+    let passes: usize = header.next().unwrap().parse().unwrap();
+
+    while let Some(group) = lines.next() {
+        let (label, count) = group.split_once(' ').unwrap();
+        let count: usize = count.parse().unwrap();
+        let bits: Vec<u64> = (&mut lines)
+            .take(count)
+            .map(|line| u64::from_str_radix(&line, 16).unwrap())
+            .collect();
+        let duration = if ty == "f32" {
+            let values: Vec<f32> = bits
+                .iter()
+                .map(|&bits| f32::from_bits(u32::try_from(bits).unwrap()))
+                .collect();
+            fastest_trial(trials, passes, || {
+                for &value in &values {
+                    hint::black_box(ryu::Buffer::new().format_finite(value));
+                }
+            })
+        } else {
+            let values: Vec<f64> = bits.iter().map(|&bits| f64::from_bits(bits)).collect();
+            fastest_trial(trials, passes, || {
+                for &value in &values {
+                    hint::black_box(ryu::Buffer::new().format_finite(value));
+                }
+            })
+        };
+        writeln!(stdout, "{label} {}", duration.as_nanos()).unwrap();
+        stdout.flush().unwrap();
+    }
+}
+
+fn fastest_trial(trials: usize, passes: usize, mut pass: impl FnMut()) -> Duration {
+    let mut duration = Duration::MAX;
+    for _trial in 0..trials {
+        let begin = Instant::now();
+        for _pass in 0..passes {
+            pass();
+        }
+        duration = Ord::min(duration, begin.elapsed());
+    }
+    duration
+}
@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|bits: u64| {
+    let float = f64::from_bits(bits);
+    if !float.is_finite() {
+        return;
+    }
+
+    let mut buffer = fpfmt::Buffer::new();
+    let repr = buffer.format_finite(float);
+    assert!(repr.len() <= fpfmt::MAX_LEN);
+    assert!(repr.is_ascii());
+    assert_eq!(repr.parse::<f64>().unwrap().to_bits(), bits);
+});
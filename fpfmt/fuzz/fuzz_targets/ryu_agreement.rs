@@ -0,0 +1,36 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|bits: u64| {
+    let float = f64::from_bits(bits);
+    if !float.is_finite() || float == 0.0 {
+        return;
+    }
+
+    let (d, p) = fpfmt::to_decimal(float);
+    let (ryu_d, ryu_p) = decompose(ryu::Buffer::new().format_finite(float));
+
+    // Both pick the shortest decimal that round trips, and of those the
+    // nearest one, rounding ties to even, so they agree on every digit.
+    assert_eq!(
+        (d, p),
+        (ryu_d, ryu_p),
+        "{float:?}: {d}e{p} vs {ryu_d}e{ryu_p}"
+    );
+});
+
+// decompose parses ryu's output into digits d and exponent p such that
+// the absolute value is d * 10**p, with trailing zeros removed from d.
+fn decompose(repr: &str) -> (u64, i32) {
+    let repr = repr.trim_start_matches('-');
+    let (mantissa, exp) = repr.split_once('e').unwrap_or((repr, "0"));
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let mut d: u64 = format!("{int}{frac}").parse().unwrap();
+    let mut p = exp.parse::<i32>().unwrap() - i32::try_from(frac.len()).unwrap();
+    while d.is_multiple_of(10) {
+        d /= 10;
+        p += 1;
+    }
+    (d, p)
+}
@@ -0,0 +1,331 @@
+// Shortest formatting of IEEE 754 binary128 values, the same algorithm as
+// for f64 scaled up: mantissas are left-justified in 128 bits instead of 64,
+// and powers of ten have 256 bits instead of 128. The values are passed as
+// their bits, since f128 is not stable.
+
+use crate::{I2A, Mantissa, bool2};
+
+mod pow10;
+
+// MIN_EXP is the exponent of the smallest normal value as unpacked below.
+const MIN_EXP: isize = -(16494 + 15);
+
+// unpack128 returns m, e such that the binary128 value with bits b is
+// m * 2**e. The caller is expected to have handled 0, NaN, and ±Inf already.
+fn unpack128(b: u128) -> (u128, isize) {
+    const SHIFT: isize = 128 - 113;
+    let mut m = (1 << 127) | ((b & ((1 << 112) - 1)) << SHIFT);
+    let mut e = ((b >> 112) & 0x7fff) as isize;
+    if e == 0 {
+        m &= !(1 << 127);
+        e = MIN_EXP;
+        let s = m.leading_zeros();
+        return (m << s, e - s as isize);
+    }
+    (m, (e - 1) + MIN_EXP)
+}
+
+// An Unrounded represents an unrounded value, like crate::Unrounded.
+#[derive(Copy, Clone)]
+struct Unrounded(u128);
+
+impl Unrounded {
+    fn floor(self) -> u128 {
+        self.0 >> 2
+    }
+
+    fn round(self) -> u128 {
+        (self.0 + 1 + ((self.0 >> 2) & 1)) >> 2
+    }
+
+    fn ceil(self) -> u128 {
+        (self.0 + 3) >> 2
+    }
+
+    fn nudge(self, δ: isize) -> Unrounded {
+        Unrounded(self.0.wrapping_add(δ as u128))
+    }
+}
+
+// The approximations of log₁₀ 2 and log₂ 10 in the crate root are only exact
+// for the f64 exponent range. These are exact for binary128's: |x| < 17000 in
+// log10_pow2 and skewed, and |x| < 5200 in log2_pow10. The products are
+// computed in i64 so that they cannot overflow a 32-bit isize.
+
+// log10_pow2(x) returns ⌊log₁₀ 2**x⌋ = ⌊x * log₁₀ 2⌋.
+fn log10_pow2(x: isize) -> isize {
+    // log₁₀ 2 ≈ 0.30102999566 ≈ 20201781 / 2^26
+    ((x as i64 * 20201781) >> 26) as isize
+}
+
+// log2_pow10(x) returns ⌊log₂ 10**x⌋ = ⌊x * log₂ 10⌋.
+fn log2_pow10(x: isize) -> isize {
+    // log₂ 10 ≈ 3.32192809489 ≈ 222930820 / 2^26
+    ((x as i64 * 222930820) >> 26) as isize
+}
+
+// skewed computes the skewed footprint of m * 2**e,
+// which is ⌊log₁₀ 3/4 * 2**e⌋ = ⌊e*(log₁₀ 2)-(log₁₀ 4/3)⌋.
+fn skewed(e: isize) -> isize {
+    ((e as i64 * 20201781 - 8384496) >> 26) as isize
+}
+
+impl Mantissa for u128 {
+    const ONE: Self = 1;
+    const BITS: isize = 128;
+    fn low_bit(self, z: isize) -> isize {
+        (self >> z) as isize & 1
+    }
+    fn log10_pow2(x: isize) -> isize {
+        log10_pow2(x)
+    }
+    fn skewed(e: isize) -> isize {
+        skewed(e)
+    }
+}
+
+// short computes the shortest formatting of m * 2**e, as unpacked by
+// unpack128, using as few digits as possible that will still round trip.
+fn short(m: u128, e: isize) -> (u128, isize) {
+    // 15 extra zero bits at the bottom of the 113-bit m.
+    let (p, min, max, odd) = crate::interval(m, e, 15, MIN_EXP);
+    let pre = prescale(e, p, log2_pow10(p));
+    let dmin = uscale(min, pre).nudge(odd).ceil();
+    let dmax = uscale(max, pre).nudge(-odd).floor();
+
+    let mut d = dmax / 10;
+    if d * 10 >= dmin {
+        return trim_zeros(d, -(p - 1));
+    }
+    d = dmin;
+    if d < dmax {
+        d = uscale(m, pre).round();
+    }
+    (d, -p)
+}
+
+// to_decimal returns the shortest d, p such that d * 10**p round trips back
+// to the absolute value of the binary128 value with bits b, with trailing
+// zeros removed from d. It returns 0, 0 for ±0. The caller must pass the
+// bits of a finite value.
+pub fn to_decimal(b: u128) -> (u128, i32) {
+    if b << 1 == 0 {
+        return (0, 0);
+    }
+    let (m, e) = unpack128(b);
+    let (d, p) = short(m, e);
+    let (d, p) = trim_zeros(d, p);
+    (d, p as i32)
+}
+
+// trim_zeros removes trailing zeros from x * 10**p.
+// If x ends in k zeros, trim_zeros returns x/10**k, p+k.
+fn trim_zeros(mut x: u128, mut p: isize) -> (u128, isize) {
+    const INV5P32: u128 = 0x62b42691ad836eb116590f420a835081; // inverse of 5**32
+    const INV5P16: u128 = 0xf60b3275305c1066e4a4d1417cd9a041; // inverse of 5**16
+    const INV5P8: u128 = 0xf36b7213ee9f5a78c767074b22e90e21; // inverse of 5**8
+    const INV5P4: u128 = 0x495182a9930be0ded288ce703afb7e91; // inverse of 5**4
+    const INV5P2: u128 = 0x28f5c28f5c28f5c28f5c28f5c28f5c29; // inverse of 5**2
+    const INV5: u128 = 0xcccccccccccccccccccccccccccccccd; // inverse of 5
+
+    // Cut 1 zero, or else return.
+    let d = x.wrapping_mul(INV5).rotate_right(1);
+    if d <= u128::MAX / 10 {
+        x = d;
+        p += 1;
+    } else {
+        return (x, p);
+    }
+
+    // Cut 32 zeros, then 16, then 8, then 4, then 2, then 1.
+    for (inv, k) in [
+        (INV5P32, 32),
+        (INV5P16, 16),
+        (INV5P8, 8),
+        (INV5P4, 4),
+        (INV5P2, 2),
+        (INV5, 1),
+    ] {
+        let d = x.wrapping_mul(inv).rotate_right(k);
+        if d <= u128::MAX / U128_POW10[k as usize] {
+            x = d;
+            p += k as isize;
+        }
+    }
+    (x, p)
+}
+
+// A Scaler holds derived scaling constants for a given e, p pair, with the
+// power of ten in pmHiLo form: hi<<128 - lo.
+#[derive(Copy, Clone)]
+struct Scaler {
+    hi: u128,
+    lo: u128,
+    s: isize,
+}
+
+// prescale returns the scaling constants for e, p.
+// lp must be log2_pow10(p).
+fn prescale(e: isize, p: isize, lp: isize) -> Scaler {
+    let (hi, lo) = pow10::get(p);
+    Scaler {
+        hi,
+        lo,
+        s: -(e + lp + 3),
+    }
+}
+
+// uscale returns unround(x * 2**e * 10**p).
+// The caller should pass c = prescale(e, p, log2_pow10(p))
+// and should have left-justified x so its high bit is set.
+fn uscale(x: u128, c: Scaler) -> Unrounded {
+    let (mut hi, mid) = mul128(x, c.hi);
+    let mut sticky = 1u128;
+    if (hi & ((1 << (c.s & 127)) - 1)) == 0 {
+        let (mid2, _) = mul128(x, c.lo);
+        sticky = bool2::<u128>(mid.wrapping_sub(mid2) > 1);
+        hi -= bool2::<u128>(mid < mid2);
+    }
+    Unrounded((hi >> c.s) | sticky)
+}
+
+// mul128 returns the 256-bit product of x and y as hi, lo halves.
+fn mul128(x: u128, y: u128) -> (u128, u128) {
+    let (x1, x0) = (x >> 64, u128::from(x as u64));
+    let (y1, y0) = (y >> 64, u128::from(y as u64));
+    let p00 = x0 * y0;
+    let p01 = x0 * y1;
+    let p10 = x1 * y0;
+    let p11 = x1 * y1;
+    let mid = (p00 >> 64) + u128::from(p01 as u64) + u128::from(p10 as u64);
+    let lo = (mid << 64) | u128::from(p00 as u64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+// U128_POW10[x] is 10**x.
+static U128_POW10: [u128; 39] = {
+    let mut u128_pow10 = [1u128; 39];
+    let mut x = 1;
+    while x < u128_pow10.len() {
+        u128_pow10[x] = 10 * u128_pow10[x - 1];
+        x += 1;
+    }
+    u128_pow10
+};
+
+// digits returns the number of decimal digits in d.
+fn digits(d: u128) -> usize {
+    let nd = log10_pow2(128 - d.leading_zeros() as isize);
+    nd as usize + bool2::<usize>(d >= U128_POW10[nd as usize])
+}
+
+// format_base10 formats the decimal representation of u into a,
+// 16 digits at a time using crate::write_base10.
+// The caller is responsible for ensuring that a is big enough to hold u.
+fn format_base10(a: &mut [u8], u: u128) {
+    let nd = a.len();
+    if nd <= 19 {
+        crate::write_base10(a, u as u64);
+        return;
+    }
+    let (q, r) = (u / U128_POW10[16], u % U128_POW10[16]);
+    crate::write_base10(&mut a[nd - 16..], r as u64);
+    format_base10(&mut a[..nd - 16], q);
+}
+
+// fmt formats d, p into s in exponential notation, like crate::fmt but with
+// up to a 4-digit exponent. The caller must pass nd set to the number of
+// digits in d. It returns the number of bytes written to s.
+fn fmt(s: &mut [u8], d: u128, mut p: isize, nd: usize) -> usize {
+    // Put digits into s, leaving room for decimal point.
+    format_base10(&mut s[1..=nd], d);
+    p += (nd - 1) as isize;
+
+    // Move first digit up and insert decimal point.
+    s[0] = s[1];
+    let mut n = nd;
+    if n > 1 {
+        s[1] = b'.';
+        n += 1;
+    }
+
+    // Add 2-, 3- or 4-digit exponent.
+    s[n] = b'e';
+    if p < 0 {
+        s[n + 1] = b'-';
+        p = -p;
+    } else {
+        s[n + 1] = b'+';
+    }
+    n += 2;
+    if p >= 1000 {
+        s[n] = I2A[(p / 100 * 2) as usize];
+        s[n + 1] = I2A[(p / 100 * 2 + 1) as usize];
+        n += 2;
+    } else if p >= 100 {
+        s[n] = b'0' + (p / 100) as u8;
+        n += 1;
+    }
+    s[n] = I2A[((p % 100) * 2) as usize];
+    s[n + 1] = I2A[((p % 100) * 2 + 1) as usize];
+    n + 2
+}
+
+// format_finite formats the finite binary128 value with bits b
+// into s and returns the number of bytes written.
+fn format_finite(s: &mut [u8], b: u128) -> usize {
+    s[0] = b'-';
+    let begin = usize::from(b >> 127 != 0);
+    let len = if b << 1 == 0 {
+        s[begin..begin + 3].copy_from_slice(b"0.0");
+        3
+    } else {
+        let (m, e) = unpack128(b);
+        let (d, p) = short(m, e);
+        fmt(&mut s[begin..], d, p, digits(d))
+    };
+    begin + len
+}
+
+// MAX_LEN is the maximum number of bytes written for a single finite
+// binary128 value: sign, 36 digits, decimal point, and a 6-byte exponent.
+pub const MAX_LEN: usize = 44;
+
+pub struct Buffer {
+    bytes: [u8; MAX_LEN],
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Buffer {
+            bytes: [0; MAX_LEN],
+        }
+    }
+}
+
+impl Buffer {
+    pub fn new() -> Self {
+        Buffer::default()
+    }
+
+    // format formats the binary128 value with bits b, writing NaN as "NaN",
+    // +Inf as "inf", and -Inf as "-inf", the same as crate::Buffer.
+    pub fn format(&mut self, b: u128) -> &str {
+        const EXP: u128 = 0x7fff << 112;
+        if b & EXP != EXP {
+            self.format_finite(b)
+        } else if b << 16 != 0 {
+            "NaN"
+        } else if b >> 127 != 0 {
+            "-inf"
+        } else {
+            "inf"
+        }
+    }
+
+    pub fn format_finite(&mut self, b: u128) -> &str {
+        let len = format_finite(&mut self.bytes, b);
+        unsafe { str::from_utf8_unchecked(&self.bytes[..len]) }
+    }
+}
@@ -0,0 +1,576 @@
+pub const MIN: isize = -4898;
+
+#[cfg_attr(not(test), expect(dead_code))]
+pub const MAX: isize = 4966;
+
+// TAB holds the 256-bit mantissas of every STEP'th power of 10 from 1e{MIN},
+// as hi, lo halves. The values are scaled so the high bit is always set.
+// Each entry is ⌈10**p * 2**k⌉, as recomputed by tests::pow10. Powers of 10
+// for binary128 span almost 10,000 exponents, too many to store them all.
+#[rustfmt::skip]
+static TAB: [(u128, u128); 366] = [
+    (0x92a54e7bc210506162294ed525a1d3b0, 0x48641bc41c563bdd66e2ccb969eae47d), // 1e-4898 * 2**16526
+    (0xeceb407c18540c20ea2b1fd7b189628d, 0x06dbf3048658c8d58b86cb6dc3e6a868), // 1e-4871 * 2**16437
+    (0xbf61b0ec60c4f5dc8ee3a73ee750b831, 0x902435a843049d384901ba7878692fdf), // 1e-4844 * 2**16347
+    (0x9a98c9daf1864c50b37fe9b396e97739, 0xd8309c6988812f959c297740a5b98485), // 1e-4817 * 2**16257
+    (0xf9c3c01370d941b8511374a959e5a888, 0x13ebc2abe4f177fa1811aa8d284d221e), // 1e-4790 * 2**16168
+    (0xc9c21e744784488e93ccc07c3125d3e0, 0xa028ae6f0218e21ba50239b0c0438a79), // 1e-4763 * 2**16078
+    (0xa2faa242a3bd093cc62364c260a887e2, 0x4c2692ffb4103a610a1b8776e70cec82), // 1e-4736 * 2**15988
+    (0x83a74673bad731a02d4643358568586d, 0x8aa6772beca9670b7b1a876b8cef15e0), // 1e-4709 * 2**15898
+    (0xd4b293e9f5013eed4f0d5bb6a3c04ab7, 0x54da1a9b81ba3cf31e936375bd211700), // 1e-4682 * 2**15809
+    (0xabd0d3928055591403059d32d4d75a61, 0x5dfdcee96f86cf034eddcd3b3edce06d), // 1e-4655 * 2**15719
+    (0x8acaa8ec1f481057356691f7702e7e5f, 0xd9d66225f3f0acd8a25d642b86404d97), // 1e-4628 * 2**15629
+    (0xe03ae07da3fdeea948bf93a385742581, 0x313be65134d8239cb0732e29a06ddf94), // 1e-4601 * 2**15540
+    (0xb521acb8ffc9f4d15d47846ef7b9cd04, 0xed668925ce489719f6a41b5ec142003b), // 1e-4574 * 2**15450
+    (0x92511ffae8dec6d2504632c46862aba8, 0xb5b74f51f43324b3a8206aa0d19074d8), // 1e-4547 * 2**15360
+    (0xec633fc4d435328bb9e09a79bb6fdc3d, 0x7db231e93ad9bcbcc270ca15a83280bd), // 1e-4520 * 2**15271
+    (0xbef3d4344900f1a081cc1760eb822007, 0xc5c724821a10d1149a48ffd1e4e586c4), // 1e-4493 * 2**15181
+    (0x9a400ae358fb7f0551a1ca2616aa0f0a, 0xe238bd8167b5adffc834a072b356dcf3), // 1e-4466 * 2**15091
+    (0xf9345f9ad9a1e5139719b93133ccc69b, 0x5baebc1fd3e9fc536192452e381e6f06), // 1e-4439 * 2**15002
+    (0xc94e4cd190f20a5d13009a37eea201a5, 0x914ed6e5ff4dde8707cc153c5af402b1), // 1e-4412 * 2**14912
+    (0xa29d1379b49f4f23f0589e798ece14e0, 0x506ddc4f3addb42b200e07be83fe3e76), // 1e-4385 * 2**14822
+    (0x835bb3297fbb7350a979fe0700a1a8da, 0x3e51f60fe99b89fc183778ab1e7a9468), // 1e-4358 * 2**14732
+    (0xd4387aae6002e84b3307aaaa80c5041d, 0xc45041a5df7776512f979e8b3ffb6a65), // 1e-4331 * 2**14643
+    (0xab6e322e450afa09e42fd04639a19c18, 0xbdac0a62dff5b2e268b882238413022e), // 1e-4304 * 2**14553
+    (0x8a7afca0558b92528d4d35b31a8d06c0, 0x35cdfbc46a449e10fe908ec116816f8c), // 1e-4277 * 2**14463
+    (0xdfba287f4fc5840cdccfde61c8ae0263, 0x9a9a6aee8ddecc9af2a7fc80511a0892), // 1e-4250 * 2**14374
+    (0xb4b9b2507679ee038c3b882731780db2, 0x05dae7f875f435c0cfa79cc4bdc44861), // 1e-4223 * 2**14284
+    (0x91fd21cd1460cc918eaf7be93daedf0e, 0x5855137aaeb315c81fe40d646a516516), // 1e-4196 * 2**14194
+    (0xebdb8d20055c9d143d4a9ef4994b870b, 0xcf513f29d8c148e0a62212306b53e41b), // 1e-4169 * 2**14105
+    (0xbe86368d1fe6f5b1db58ad43759bbd76, 0x6509706a055d0d8c0fe9d37b639958a2), // 1e-4142 * 2**14015
+    (0x99e77edd862f12462d9ed2409e71af4d, 0x44840fa46b1af7e7cab41eb2e24ba5d1), // 1e-4115 * 2**13925
+    (0xf8a551706112897c4268a54f70bd28c4, 0xae16192410d3135ed1f4628316b15c7b), // 1e-4088 * 2**13836
+    (0xc8dabdab2903c7c077640f894814a490, 0xfd6a6511014dc057622326ca136410ef), // 1e-4061 * 2**13746
+    (0xa23fba65aa89b5c9cd01dce6cc32a5e3, 0x6167e0192b2d5c12f4f1623122362782), // 1e-4034 * 2**13656
+    (0x83104b4189664a8223203dffee7baa63, 0x0fe87b72822a06d05670f6cb919dc4bd), // 1e-4007 * 2**13566
+    (0xd3bea789f01290bac9d4cf35e35d5015, 0x893568f6a0ed921e4e81bb065009eac5), // 1e-3980 * 2**13477
+    (0xab0bc968651e1e6993466c45e642d8e5, 0x0e3c7943f2230011e17b9e1c57a5cf64), // 1e-3953 * 2**13387
+    (0x8a2b7e10fe90125b6bb427a5fb19875c, 0x60ef10cb4cba944992145af48c3ceb0f), // 1e-3926 * 2**13297
+    (0xdf39ba6500407f3a60b7de3f2e223418, 0x99f1c7a924d0513713ac16b504208105), // 1e-3899 * 2**13208
+    (0xb451f3982a13e43373e14bc8e5edd724, 0xed3ebb135bb7a7fde9508409fc5a1c43), // 1e-3872 * 2**13118
+    (0x91a953d687082c3c0484032285bd6a9b, 0x9c59623539712e695aeb41f0ce9327cb), // 1e-3845 * 2**13028
+    (0xeb542860da9bc7d8c3f29d230036529b, 0xfafc1dc8fd12a6f8708f80846216207b), // 1e-3818 * 2**12939
+    (0xbe18d7d2b180a436d4d4c403628615b1, 0x02d31151a4ef061537e3e1c5d6e757f4), // 1e-3791 * 2**12849
+    (0x998f25ac3a8753848edcaa6af85896a4, 0xf632fcd35dc22f2987da9dab6513492a), // 1e-3764 * 2**12759
+    (0xf8169564c7e9d6c2e49718286d8799d0, 0x253f6bf42472b74ecca8fe19b81dcb6e), // 1e-3737 * 2**12670
+    (0xc86770dae541573c77160d1a8281e51a, 0x16b30cce8a252f2fbefee04ec3e1da3f), // 1e-3710 * 2**12580
+    (0xa1e296e7b0f686d57660921a7cb20738, 0x482f3231831fb7eabf611f149a6887cb), // 1e-3683 * 2**12490
+    (0x82c50ea2f0505aeb013536fafeef164b, 0x7178ae273b98bea80483f3d9ee75b6b7), // 1e-3656 * 2**12400
+    (0xd3451a5468f7549a008ac939fa1abb14, 0x8b4b134da794973f75c6e6571c322409), // 1e-3629 * 2**12311
+    (0xaaa99920601abdfed149ca9175896a57, 0x94d0927225ff5492eaca6eacdc72bd77), // 1e-3602 * 2**12221
+    (0x89dc2d23d9202901e3b8b6a4bed75ca5, 0x2b550c149bd2495abde501bae1149ec1), // 1e-3575 * 2**12131
+    (0xdeb996044abc25fc0d0345c48caecdf0, 0x08e2a88445348c20afaf25c5c4e15884), // 1e-3548 * 2**12042
+    (0xb3ea706dd701b1979ad3717a3c4bc2a1, 0xbaa468c6ab619616be4a858c1faf3d9e), // 1e-3521 * 2**11952
+    (0x9155b5fb9333523fc3a00e868d5b5901, 0x23ffe8c8ccb2f8264f6f3e9f41ade87f), // 1e-3494 * 2**11862
+    (0xeacd115a9c7e567c3524b60ed3a0b28d, 0xa257291864a93786ca00c460e54de5bb), // 1e-3467 * 2**11773
+    (0xbdabb7e0de9fe022afc67523f435a5f2, 0xa4b5eb794fe1fe79a060288107240420), // 1e-3440 * 2**11683
+    (0x9936ff3248343ac1367ba5b556eab095, 0x60e7200a89eca1e21b6419eb2a825e1a), // 1e-3413 * 2**11593
+    (0xf7882b48ea05b5d200b24cd338fd3779, 0x6a1bc7874c016f041ba9c8a577c35d71), // 1e-3386 * 2**11504
+    (0xc7f4663ab11b46aaf912ced4d54a2b93, 0x58d805132e10d1e94816c444683cd93b), // 1e-3359 * 2**11414
+    (0xa185a8e10512bb3f2d22a5f73de44d43, 0x4a3fe8fa377a0e55495ad6036334abb1), // 1e-3332 * 2**11324
+    (0x8279fd34db3e23eb90016bf3a7f9f8ec, 0x068416b34b5d1d11cd8acd1225f889d0), // 1e-3305 * 2**11234
+    (0xd2cbd2e5a5912217c680fa4b739064b3, 0xf37a6a4dab1c7949a0dfbdfc652633ae), // 1e-3278 * 2**11145
+    (0xaa47a135c835270467c9ee8e6a55f848, 0xa755a2ee037ba6400cb0612a03d708fd), // 1e-3251 * 2**11055
+    (0x898d09beb318ba4edd1f8f7bed51eec0, 0x78eea2717732154c1fb93ef02d687835), // 1e-3224 * 2**10965
+    (0xde39bb32dcdf277166bf06c498a98220, 0x400eab3567b7c42439962637496f458b), // 1e-3197 * 2**10876
+    (0xb38328af4d587fad708e3d8c0e98cd64, 0xc76322cc36cb64b3cf82a398bfb2586a), // 1e-3170 * 2**10786
+    (0x910248209b2428ad8da38d9e1658e044, 0x909f14cba9dc581c7b0e036d87e89313), // 1e-3143 * 2**10696
+    (0xea4647e0ad3b4f61a70859f46f44311a, 0x39f20433d972ee811a042921f865fe1e), // 1e-3116 * 2**10607
+    (0xbd3ed6939cd2df215f8d7250db61e4ab, 0xa3026ee16925180497dc24c71a611aca), // 1e-3089 * 2**10517
+    (0x98df0b529225c77b8bda1445de26964b, 0xd6df4bb81e0f973d42599e72dc516523), // 1e-3062 * 2**10427
+    (0xf6fa12edbe53beeb9302ee9edf663216, 0x63e320fea8a9e5847509191283a422f8), // 1e-3035 * 2**10338
+    (0xc7819da48dde47904e6570cd8536b61f, 0x961cd07660c85d2ff8487e84cc0cb08d), // 1e-3008 * 2**10248
+    (0xa128f032f5b3d27bfcbe419316f1cd24, 0x6229020dc8ad3335bf3171a58ab68668), // 1e-2981 * 2**10158
+    (0x822f16de7f37cb9f109259bdd42ece39, 0x507838f744b77ad336dda8fc51186e7a), // 1e-2954 * 2**10068
+    (0xd252d11597cb76f661922956afe348af, 0x31d12567901d52d0c85add4065ebf7b3), // 1e-2927 * 2**9979
+    (0xa9e5e188423f484b97ba45cf0f7efb61, 0xd440cc18a5fab993ef63bd5243120676), // 1e-2900 * 2**9889
+    (0x893e13c769604eeb9f881fb8f1ac1503, 0xb9154f5fd30435d96db86638ff7d2ef2), // 1e-2873 * 2**9799
+    (0xddba29c67c9ba1c89b1da02e8f113f09, 0xec00e2a652ed864b320411a355b04d3a), // 1e-2846 * 2**9710
+    (0xb31c1c3a70cd7cb72e2d8d8570b1c553, 0xade55798c6248949b163400b79b2b3fb), // 1e-2819 * 2**9620
+    (0x90af0a2a10f6f849b93a5f1bdc43b0d2, 0x494ca75e5e8024b52879ff31ea87a9cd), // 1e-2792 * 2**9530
+    (0xe9bfcbc688a65f624d0447208f3d177f, 0x4012e15015c7bcb7a694e67207b25771), // 1e-2765 * 2**9441
+    (0xbcd233c6f658425c630169520c70d76f, 0x99a0d141abf5d74e6fc515cc5110ad14), // 1e-2738 * 2**9351
+    (0x988749f00c026328ffc4aa0abeeab694, 0x72412a7cbe67941859a9e81ada1958a4), // 1e-2711 * 2**9261
+    (0xf66c4c2456c1b0d69d07579746b2e880, 0xca750290643fe3459c94e3a24ff5a928), // 1e-2684 * 2**9172
+    (0xc70f16f292a6a2a7aa0f15cadfa223b9, 0xda4320f1bea601a6dd24f2805a9f7bd5), // 1e-2657 * 2**9082
+    (0xa0cc6cbee34daf7e5fe6fe71fda0c2c1, 0x05deab5c2cd410fa458d3ca71422d8ce), // 1e-2630 * 2**8992
+    (0x81e45b871f80eea2d171884992e43ce0, 0xe94b948d885d3024caf424fb46b46609), // 1e-2603 * 2**8902
+    (0xd1da14bc489025ea3736730a9e47fef8, 0xc9879fc42869f610ba2431d885f2b7da), // 1e-2576 * 2**8813
+    (0xa98459f7859e01897b9d65567520040c, 0xe91bd948126806e477c54bec66c590f3), // 1e-2549 * 2**8723
+    (0x88ef4b23e7de7240caf8e57971493ee5, 0xa0ff446b95dd057585d64f3bc6500f88), // 1e-2522 * 2**8633
+    (0xdd3ae19508212ffdf50bed647e6bb3c1, 0xa9da034b42e4aead6f7a84b343128349), // 1e-2495 * 2**8544
+    (0xb2b54aed38aa97b5015d922ff179f7a1, 0x79bbcfe0d697b3f36865f5ac97e3e1b8), // 1e-2468 * 2**8454
+    (0x905bfbfc76994ed9747d27284bb6034a, 0xfad2f315f00e4a8cdd05e5ee8db1eaa5), // 1e-2441 * 2**8364
+    (0xe9399cdfc42125f8df8cc8d434c2daf0, 0x5b6a69be4eb7650101ea15001ecc684a), // 1e-2414 * 2**8275
+    (0xbc65cf570a133614dfa61f8e0a4614fd, 0x2a9dfc5b2fadf290e3d193a9f6b076d9), // 1e-2387 * 2**8185
+    (0x982fbaedba1d4931795a917d40217583, 0xce7b17449614a8c7bca274bc0c91ba5f), // 1e-2360 * 2**8095
+    (0xf5ded6bde02df0f79434b4ced787c29e, 0x26790a25534bf0027af2aaae1d08d826), // 1e-2333 * 2**8006
+    (0xc69cd1feec53b2a3b2a8875c6a3719dc, 0xab9451af67551673139cfd6a6b809577), // 1e-2306 * 2**7916
+    (0xa0701e663fe87b888c0ec4a0dc36d58d, 0x64cb7d80c2956f6ca15761b92709ade2), // 1e-2279 * 2**7826
+    (0x8199cb160d9074909c477dafa351213d, 0xcabcf3f0b877cf770efda40e2de01ebd), // 1e-2252 * 2**7736
+    (0xd1619db1d7ba2390abf358355cc3356f, 0xfc7dcdf4c351cf568f41736f61d27c54), // 1e-2225 * 2**7647
+    (0xa9230a635c3e79c6d582ed222188da15, 0xc3f3e972e27bf34d218698be038915c7), // 1e-2198 * 2**7557
+    (0x88a0afba29731589e3e888fd761a9fe2, 0x386564b5492ebb7e1b191dced147a4ff), // 1e-2171 * 2**7467
+    (0xdcbbe27475ceff9cd18f7aece789392b, 0x4c301eefc58cd34affe1aa505617bb67), // 1e-2144 * 2**7378
+    (0xb24eb4a5afc342d5c8fb526f6be92226, 0x267c7f16df2d7f5923d58e3ea40b8679), // 1e-2117 * 2**7288
+    (0x90091d7c5dc0eaa962fbec6869f64ccc, 0x355d1c8e53f42a4bd3d0d9ba0fbc1e17), // 1e-2090 * 2**7198
+    (0xe8b3bb000e8c89dda246471738f24664, 0x68896129fa7d5ef503f3b15e619b097a), // 1e-2063 * 2**7109
+    (0xbbf9a9200b7f980f124d2d2e0bf2675f, 0x873cc1ca17429acc8a6cd6ddd1f287b9), // 1e-2036 * 2**7019
+    (0x97d85e2eb16cf46da17e71e33b445149, 0x4585b52cb64a480721c052b53d1b6174), // 1e-2009 * 2**6929
+    (0xf551b28ba258144e9502c4c60ac983ec, 0xd8255990b4c17b23ef37777ee6ff2eae), // 1e-1982 * 2**6840
+    (0xc62acea3dd7b661f0b244142060dc894, 0xaf5373f0bdece2e37454ef2b3b416145), // 1e-1955 * 2**6750
+    (0xa014050a8f168ecd0cd0a01396a49e31, 0x0e305745f3c6fa14c39e1bf9abc668b9), // 1e-1928 * 2**6660
+    (0x814f6572a9086929c5a57b58374710d2, 0xa06fcc93f6f85b04ee0b09af26b29f2f), // 1e-1901 * 2**6570
+    (0xd0e96bce7c085afabd222ed22e6d94ff, 0xd78e523f73688b85feafbc1d4471d76b), // 1e-1874 * 2**6481
+    (0xa8c1f2aba28b7beec35865701d390f89, 0x58443c7a07cbf515600de5981c7eaedb), // 1e-1847 * 2**6391
+    (0x8852417037edf7da9a8a962eda71e86d, 0x2425940e875a525a86e7d9e095b5834a), // 1e-1820 * 2**6301
+    (0xdc3d2c3ad425ee7d7a6c481d67fe9b21, 0x3427d7e1acc624bdf03c1ea82d8a74f6), // 1e-1793 * 2**6212
+    (0xb1e85941f4693c5bad04c634adb7a62a, 0xeb4b54f18146fbe636332883340cecd0), // 1e-1766 * 2**6122
+    (0x8fb66e8e67e2ab4a97bd62d70cc060d2, 0xd1063c826a6ed9e1de2353c4e4a03b37), // 1e-1739 * 2**6032
+    (0xe82e25fb303a160e302fcf9150638209, 0x3efe74a0e74c0e0680507e73203c05b8), // 1e-1712 * 2**5943
+    (0xbb8dc0fe42a624cb2b3daf092f4d0870, 0x8d991e3bcdfcaa1031e18b213b4ac052), // 1e-1685 * 2**5853
+    (0x9781339617819223e37f54374fecbacd, 0x8b1b2e83c35ed1213cbcec6dd1ea9c9c), // 1e-1658 * 2**5763
+    (0xf4c4df5effd171563eb62c4cedb53782, 0x606ccbb50d5b2441a21f55108d433233), // 1e-1631 * 2**5674
+    (0xc5b90cbbbe5dc8b8b27689fdf26d3e14, 0x1ca445d55d31106fc296419b6d967b79), // 1e-1604 * 2**5584
+    (0x9fb8208d65ea5eda5a0eb896edc89b54, 0x09102915726a9905c6a9e1433c7c9d9d), // 1e-1577 * 2**5494
+    (0x81052a845fadda302dbd767d9029fb68, 0x44e962fd622e65978af63d90b0e3df91), // 1e-1550 * 2**5404
+    (0xd0717eea831089c6fb8ddd87b6d5b0ef, 0x41de75bc5f79e0571778789a23ab9ac4), // 1e-1523 * 2**5315
+    (0xa86112b04762d978d61369c8fb8e0755, 0x6677843358545049d1ad30495caa8923), // 1e-1496 * 2**5225
+    (0x8804002c2c061312f6c8b5436420c2d5, 0x9e63ce6b25cb18ec4d62756e5afad734), // 1e-1469 * 2**5135
+    (0xdbbebebe49bab07b51333c90edd73323, 0x053f5a64b2c890df8f4576db062b120a), // 1e-1442 * 2**5046
+    (0xb18238a038615df0dd99e87ce165258f, 0x474e8ca729fddc2539af5941920c0a7e), // 1e-1415 * 2**4956
+    (0x8f63ef1746298782ed367eea5f9916be, 0x3a51141209df10e4648a0ba7aa231f5e), // 1e-1388 * 2**4866
+    (0xe7a8dda50add5f3c38513d0dbf906e48, 0xbffd1f06c660a709e746d0e5629beb58), // 1e-1361 * 2**4777
+    (0xbb2216ce0c10ab87ae396aa72b78aeaa, 0x1c129b84af2a18d84d14b465f31472cc), // 1e-1334 * 2**4687
+    (0x972a3b07227b7a80fb74ea4e2db586c6, 0xed05487aa6785e2c4fb46bdbd0de4ff5), // 1e-1307 * 2**4597
+    (0xf4385d0975edbabe1f4bf6653cd3b977, 0xddee7f83569c8b33cd5140f638d331b4), // 1e-1280 * 2**4508
+    (0xc5478c20fcd893581c478db63fd474c8, 0xe4c545b373c08b0c155ff45ce16e1f8f), // 1e-1253 * 2**4418
+    (0x9f5c70d06aec72ce157660caad9c3f7f, 0xaf206bcfb4916f945052638ac5d2d2b0), // 1e-1226 * 2**4328
+    (0x80bb1a32ad60b9ea825b629b1808f4d9, 0xe6be62e28b2a529e98749f0f99f7bbd7), // 1e-1199 * 2**4238
+    (0xcff9d6de513223c68fe7fc9335645cb8, 0x68c25356f2d1227563daa69f1ae5380e), // 1e-1172 * 2**4149
+    (0xa8006a514c0ad32b0a3fcd544f8fa599, 0x3042c22ae491391702c29c08f7709ab9), // 1e-1145 * 2**4059
+    (0x87b5ebd42d510dbf93f25a992544837b, 0x078d1b0846f6cd40ba32d49a6fc8b7cd), // 1e-1118 * 2**3969
+    (0xdb4099d51527fd1eb77c73bea62bb7fe, 0x70cb7ebc722216fb8745618b04d7a934), // 1e-1091 * 2**3880
+    (0xb11c529ec0d87268c6f075c4b81fc72d, 0x6d282fe1b7112879fd349cf00ba1e09b), // 1e-1064 * 2**3790
+    (0x8f119efbb96d886dbef5a1e6da0684fe, 0xad6c207672c1162bf051bd9e623b98cd), // 1e-1037 * 2**3700
+    (0xe723e1d1997d719e582f08230fca286e, 0x5b2b7260088a8ad5b735e05417358467), // 1e-1010 * 2**3611
+    (0xbab6aa6bd8be490970f4b9ad9f23e3ad, 0x5cd19ce6ca64c1c4c93ed209a9bbd875), // 1e-983 * 2**3521
+    (0x96d374651901ae88ec9a498a3df7d155, 0xf58423e2370f85800402faa69ebb9bd7), // 1e-956 * 2**3431
+    (0xf3ac2b5c9cb3a2fb99d5bcf5be9c59cd, 0x460b44b2b80e2910caf2f8ec64f85d78), // 1e-929 * 2**3342
+    (0xc4d64cae1c5ac394e73067c39fafa2fd, 0xfd0303987a0582cf7c11a015ebea21e5), // 1e-902 * 2**3252
+    (0x9f00f5b556115d4c9e05e09311f21caa, 0xb481194ab3cf3931a287a5c5686e361e), // 1e-875 * 2**3162
+    (0x807134651c13c65113fda689fcff66e2, 0x777d3e8451a305a9431c912f2c407e14), // 1e-848 * 2**3072
+    (0xcf82738261893e28f4933b6cd5ed0f47, 0x837329e26b6a7c4711383c94b3b68b75), // 1e-821 * 2**2983
+    (0xa79ff96ec42787f0227f93152609a101, 0xdf79b5bc31c092292715372494a3c3e4), // 1e-794 * 2**2893
+    (0x8768044e723ab1e318cd0f77cbcae83a, 0x3023378dac2cbfba043b483b043d1ceb), // 1e-767 * 2**2803
+    (0xdac2bd558d00c53623b0d9b5fd389e46, 0x382d925738097dc1c7be78b4d67dc179), // 1e-740 * 2**2714
+    (0xb0b6a71be65811ea0a3b2a518d250e66, 0x55d928f9a45d84b0504c615bba900a3c), // 1e-713 * 2**2624
+    (0x8ebf7e20922ac9ca0a736a3d71e82f97, 0xc58681d23f0c40309c426df9446b25a2), // 1e-686 * 2**2534
+    (0xe69f3254f066471e440a9880561712f3, 0xc9c03720be2f483f6eed2bce1da16977), // 1e-659 * 2**2445
+    (0xba4b7bb42e17a92554d322a2add13eb5, 0xa0328bafc12f8c7aca437f47537d3215), // 1e-632 * 2**2355
+    (0x967cdf9352385b7d3bc0ae0fc92ab518, 0x36b5419b6d05f56d6d941b9bfd41d180), // 1e-605 * 2**2265
+    (0xf3204a2a26cd88ac34813380616cce74, 0x70e72028f1643759ee67bb9fbca60418), // 1e-578 * 2**2176
+    (0xc4654e3db5d83a3e175fa33d5dcb21b8, 0x4c481521fdccd4a02dc890cfc77f79cb), // 1e-551 * 2**2086
+    (0x9ea5af1df0afbc39aa1736e32354e3ee, 0x979b7dc805b5f6d9208e4c61ca445840), // 1e-524 * 2**1996
+    (0x8027790343c474e0917474a07ee8fa50, 0xc8fd69f087119ed9ce06943d7782fec8), // 1e-497 * 2**1906
+    (0xcf0b54af45e1822a12bd40a4b6b0b142, 0x9ab49c8ed8a3c53d0eae9b925139bf18), // 1e-470 * 2**1817
+    (0xa73fbfe8d5b069bee68c9c05ca6695cb, 0x189ec3d2eec3c34e5fca50ab672cd506), // 1e-443 * 2**1727
+    (0x871a49813ffc68a61a4eb006f7ce07de, 0xd7cc4fee9ed6c1d9118873312db79243), // 1e-416 * 2**1637
+    (0xda4529161fc27059d4656abdaccd75f5, 0x3aafb05bfef9926facdc3f77018dbeb1), // 1e-389 * 2**1548
+    (0xb05135f614bb847d8da339e787d6807a, 0x278497949f74273b8e2215d79676b04b), // 1e-362 * 2**1458
+    (0x8e6d8c6ab0787f72fe30f0f5e50e20f7, 0x41e4f37bb6189a6d88b0120017f434c5), // 1e-335 * 2**1368
+    (0xe61acf033d1a45df6fb92487298e33bd, 0xadd7728c20b99bd18c0ad035d9a536fe), // 1e-308 * 2**1279
+    (0xb9e08a83a5e34f07daf5ccd93fb0cc53, 0xfa73fb2b6ce8f276bb66b3a64da4b986), // 1e-281 * 2**1189
+    (0x96267c7535b763b54bc1558b2f3458de, 0xbec094946d4865dfd91a9934feca9691), // 1e-254 * 2**1099
+    (0xf294b943e17a2bc43e6f5b7b17b2939d, 0xf9e50a9c216216bf166249dc4fed61fa), // 1e-227 * 2**1010
+    (0xc3f490aa77bd60fcbedbfc4411068a9c, 0xf7954e1cea8679b1db09434273068b94), // 1e-200 * 2**920
+    (0x9e4a9cec15763e2e9a598e4e043287fe, 0x037ac5d0813b1727a14de90217d50b47), // 1e-173 * 2**830
+    (0xffbbcfe994e5c61ffdf17746497f7052, 0xf089520f8a078e2ac59b8d5fe89bf9cb), // 1e-146 * 2**741
+    (0xce947a3da6a9273e733d226229feea32, 0xc7047924233bf92002ea601087a1c0b2), // 1e-119 * 2**651
+    (0xa6dfbd9fb8e5b88ecb4ccd500f6bb952, 0xea3b36b814df04661d3cdfdafd94a6e3), // 1e-92 * 2**561
+    (0x86ccbb52ea94baea98e947129fc2b4e9, 0xbb4cbe9a473bbc273e753c4adc70c900), // 1e-65 * 2**471
+    (0xd9c7dced53c7225596e7bd358c904a21, 0x2cca49a15e8a06839aa1068d3a9012c8), // 1e-38 * 2**382
+    (0xafebff0bcb24aafef78f69a51539d748, 0xf2ff38ca3eda88b093e034f219ca40d6), // 1e-11 * 2**292
+    (0x8e1bc9bf040000000000000000000000, 0x00000000000000000000000000000000), // 1e16 * 2**202
+    (0xe596b7b0c643c7196d9ccd05d0000000, 0x00000000000000000000000000000000), // 1e43 * 2**113
+    (0xb975d6b6ee39e436b3e2fd538e122b44, 0xe083ca81200000000000000000000000), // 1e70 * 2**23
+    (0x95d04aee3b80ece5bba1f1d158724a12, 0xe35884219d96decad95d722140000000), // 1e97 * 2**-67
+    (0xf209787bb47d6b84c0678c5dbd23a49a, 0x0612c3c5ce15e554dccc6ca29b3e9df1), // 1e124 * 2**-156
+    (0xc38413cf25e2d70dfef5138519684aba, 0xbd12efef23aa51593f477ffe58253e80), // 1e151 * 2**-246
+    (0x9defbf01b061adab3a0888136afa64a7, 0x1360aa0f44cd064fc3501fbb08d428ac), // 1e178 * 2**-336
+    (0xff290242c83396ce7e67047175a15271, 0x2d7d9d59a4583f261f0c9ce92e97d652), // 1e205 * 2**-425
+    (0xce1de40642e3f4b936251260ab9d668e, 0xd9e10d38493f193bdb753d937905bb01), // 1e232 * 2**-515
+    (0xa67ff273b84603568a892abaf368f137, 0x37ff96f2c26fa7b16e613c34eb02bcdc), // 1e259 * 2**-605
+    (0x867f59a9d4bed6c049ed8eabcccc485d, 0x5d86c07a6c671fafa79e946c200627b0), // 1e286 * 2**-695
+    (0xd94ad8b1c738087418375281ae7822bc, 0x2562f146216148d0d8062f819b07da2a), // 1e313 * 2**-784
+    (0xaf87023b9bf0ee6aeb8fad7c7f8680b4, 0x45da41e1f4b98e377e11b306bf508526), // 1e340 * 2**-874
+    (0x8dca36028bf3d489350595680d93ffc3, 0xd15f10bb4f481bb792f77d3f7877a4b2), // 1e367 * 2**-964
+    (0xe512ec31eba6a6413c0bae017a41c5cc, 0xa247268eb36a8b895d97b9394bd743c2), // 1e394 * 2**-1053
+    (0xb90b602ac97a8e486e46832bf4695572, 0x53e9db8430a67f3ecca35eb940bb8e6d), // 1e421 * 2**-1143
+    (0x957a4ae1ebf7f3d3a7ea9c8838ce9437, 0x3c11d8430d5c4802ffa6738a27dcf7a4), // 1e448 * 2**-1233
+    (0xf17e87a3a2110d33ae58b0e327c0edc0, 0x6fcbbcd96c2d2c61646bb8be056494cb), // 1e475 * 2**-1322
+    (0xc313d7869981251050c95436c8b4f3b8, 0x857f8ccdfe3ca7b26137948e67597cf3), // 1e502 * 2**-1412
+    (0x9d951540beb301fda294d2fc292189ee, 0x3e258c3769983736c9cf9114620fb695), // 1e529 * 2**-1502
+    (0xfe9688e1a54cfe670c6b72b99bd62b54, 0xb5c68b9fa34c99073654bb9e334f5973), // 1e556 * 2**-1591
+    (0xcda791e1f01e4ae7866de911391ab5e0, 0x42134e7dc92350283d5a03c72c7422e5), // 1e583 * 2**-1681
+    (0xa6205e453083af1132d6f580c2dd0044, 0xbecd517b4b1a23ed1cd6b9eae8bc84e9), // 1e610 * 2**-1771
+    (0x8632246c6fea19b6e0258dde177d97ab, 0x31c71fe73c8881744a9090f3d44617b0), // 1e637 * 2**-1861
+    (0xd8ce1c3a2fffaea73a4181cdda0d6e24, 0x514478d1fcd48eea11d12e0ffe8c889e), // 1e664 * 2**-1950
+    (0xaf223f642cae3587615e6dd61ec690a6, 0xbfc62284fb60bc7a99fad9b9a7e29520), // 1e691 * 2**-2040
+    (0x8d78d11a5706cd8d97b9d56308a0c288, 0xe586396ce54a14bc91466466a9328e69), // 1e718 * 2**-2130
+    (0xe48f6c5b2611d87cbc2f14752bc1f903, 0xb93b24548e297426a873fd3c92ccfb21), // 1e745 * 2**-2219
+    (0xb8a126bc0e3f4b4e6a826c5e18681b28, 0x1adf56de0f4d035896ffab1d01dff559), // 1e772 * 2**-2309
+    (0x95247c33dfd6e570b2769631d1ba79f8, 0xae60e8c4abc01c82f963794baeae7b75), // 1e799 * 2**-2399
+    (0xf0f3e68dc6d58b91527fa8560919fd75, 0x2bb34d2a7f42f16c6d38491c6b819647), // 1e826 * 2**-2488
+    (0xc2a3dbabc12477e0051e83661c61f4ea, 0x63d51663258fecfc07acee8d336ce3c1), // 1e853 * 2**-2578
+    (0x9d3a9f8b4ee575ddfad4bef696066546, 0x8f5b89eff5cf31d73f1de24d5642dc64), // 1e880 * 2**-2668
+    (0xfe046395cbe1c9ae1fcdd903101b910f, 0x6a1f7ccb71d5e5236bf6e40a6b425073), // 1e907 * 2**-2757
+    (0xcd3183a99a60339d4199e17bf905cbef, 0x98efd55c8341162b6bec44e14cc8c763), // 1e934 * 2**-2847
+    (0xa5c100f4907a83c63a3f4ec27617824f, 0xeac82c9484ad96726c1489990b400c37), // 1e961 * 2**-2937
+    (0x85e51b813c31a00b886450197da44665, 0xdc7e270c09a8fdd39a2ce7544bdcc931), // 1e988 * 2**-3027
+    (0xd851a75d5bbc5c88e9cea6fec4cdb6ef, 0x8fd1cdd15f2a12f03df02ceaaf044086), // 1e1015 * 2**-3116
+    (0xaebdb664360fe0e27081a7fa5e3686cb, 0x823ffe25a1e20ec17fcd5c1d6f512ab4), // 1e1042 * 2**-3206
+    (0x8d279aeb83631cf7a9da6186593de3ae, 0x2d19778cc2a7c0433d59692b1bbc7330), // 1e1069 * 2**-3296
+    (0xe40c380107510c5b9021ec9f1c2fd7d6, 0xc61eb110f611c4497b13ff1cda0a40db), // 1e1096 * 2**-3385
+    (0xb8372a47a75154ed3afd8856cc1a4a90, 0xdee4b59c42355ff22ca470a7cbe75a06), // 1e1123 * 2**-3475
+    (0x94cedec7c0263d5cb5d274fef7216bed, 0x79b59e0ea55aa2e364c81147f4a6fcc5), // 1e1150 * 2**-3565
+    (0xf069950c59c2ef05edec9f000535856e, 0x5129e351ca6dffa7cc1af0292b3d6eb0), // 1e1177 * 2**-3654
+    (0xc2342019a0a0627eee1f4ea0cec13421, 0xee5eb474fa341e223fd6a500e81c3f74), // 1e1204 * 2**-3744
+    (0x9ce05dc380a4a3bacbb5a497988394b5, 0xf2235a04769709a64a6dc1425910d3db), // 1e1231 * 2**-3834
+    (0xfd72922ef766f562e9ed606499e86a43, 0xdab2c9d3c162322aac0e44fb68ef0745), // 1e1258 * 2**-3923
+    (0xccbbb93644207a2e8da1136da8a8d3c4, 0xfb40dfd41251316b83644bd4b3047897), // 1e1285 * 2**-4013
+    (0xa561da6259253f91202e275e2e6472b2, 0xe87c8e95fa932e7caebd4bb2e3516edb), // 1e1312 * 2**-4103
+    (0x85983ecec853d8aed0624b1c56032444, 0xc712f890009de19053054ffd6119584f), // 1e1339 * 2**-4193
+    (0xd7d579f22fb27a2ff4269a06098c2576, 0xa952fc5153321b07a580cca829683aee), // 1e1366 * 2**-4282
+    (0xae59671a83e3cd23ed2df5abfeaefc54, 0x84400ea7d42fdc0387055bc2373ed769), // 1e1393 * 2**-4372
+    (0x8cd6935b3ea1753dd1f4e6d421be4d35, 0xb7196c9aa6d174f5df3a292f5966e175), // 1e1420 * 2**-4462
+    (0xe3894ef83a1e51d0a0d0cdd381d0ce41, 0xb37b7cdc9c8f0b15ec9643c494514aee), // 1e1447 * 2**-4551
+    (0xb7cd6aaa939d8a1f9b52253a90fb5840, 0xd41615e0e8273841f6d694c90945bfc2), // 1e1474 * 2**-4641
+    (0x94797281463329ca0b02bb4f4331763f, 0x1cf528c35f1af80a69015be5055f6e77), // 1e1501 * 2**-4731
+    (0xefdf92f1ac19ae828d026ce66d845c8c, 0x10f6438de66478b9b4d479c5f0fe6b71), // 1e1528 * 2**-4820
+    (0xc1c4a4ab5103a70327e3b8965acfc8a6, 0x0bb1f9d489411d075a98ad0d41ae4d45), // 1e1555 * 2**-4910
+    (0x9c864fcb84c2a7b2a64809131821604f, 0x6292d5986c624cb61937800c8702f61a), // 1e1582 * 2**-5000
+    (0xfce1147cff06bd3bf5c29529b4b1e473, 0x98c8ae5a61b0f247654f4053dc3e18db), // 1e1609 * 2**-5089
+    (0xcc4632610637cad219ee0c35f3ca05a9, 0x8c9564677501ce049b858f5107808860), // 1e1636 * 2**-5179
+    (0xa502ea6f1d932fa52d2c6db74d8b45aa, 0xaf4437c61d53805aab56e9b0e7fb28a5), // 1e1663 * 2**-5269
+    (0x854b8e3bb1aa1e202fd7b4a1eaa621ef, 0x76d4ddc3934452b05ba180de50d02096), // 1e1690 * 2**-5359
+    (0xd75993cfa8befcd45e084fe1f1d00205, 0x123d154d3b6e7fef366bec381b39a938), // 1e1717 * 2**-5448
+    (0xadf55165f5075bae35242e8990e4e9dd, 0xd7cc93d2ff88afb0b42a363bf817fbab), // 1e1744 * 2**-5538
+    (0x8c85ba4ec5c02d9b75df971f5aa72411, 0x004c30b113a8f305410060fd92fcb3d0), // 1e1771 * 2**-5628
+    (0xe306b1158213ca678ed7c8b7b690682e, 0x1254801380dd649365e566f39f705a9c), // 1e1798 * 2**-5717
+    (0xb763e7c1e628dfa02ab31813ed039e6c, 0x5c18d5fe01a0e0975db256a192896b59), // 1e1825 * 2**-5807
+    (0x942437443b8634c14a551b4fd356bde8, 0x1e7660b1fb79a8ea2ddb7a57be6eac77), // 1e1852 * 2**-5897
+    (0xef55e01029539910116a9edfdc2da542, 0xb253ecbf34bd72a26fd07e1540114223), // 1e1879 * 2**-5986
+    (0xc155693c008c0688f6fc74fd0a31feb0, 0x2cf583ab6d6bc5c2a0bba357c71cf485), // 1e1906 * 2**-6076
+    (0x9c2c75859d2e4737d8e06484b94d7c65, 0xc229ae84570ff591b99e90e0ca303ff9), // 1e1933 * 2**-6166
+    (0xfc4fea4fd590b40a7a37993eb21444fa, 0xc851bb3f9aeb12111b6ff8afbe589b73), // 1e1960 * 2**-6255
+    (0xcbd0ef030fd3d967caa3508f00379026, 0xcfd22e82050e526934a4bbedc81b8af6), // 1e1987 * 2**-6345
+    (0xa4a430fb82ddcf49e0e36da6264c5c8c, 0x74cc2ed7e3227cdfea6b1060f60cb94b), // 1e2014 * 2**-6435
+    (0x84ff09aea420541c6d5eca66eb728095, 0x3eea9fe34ed7006f1eadaab009e3c3de), // 1e2041 * 2**-6525
+    (0xd6ddf4ccdb49db3ec024e7b53b3fd027, 0x3bb633256fb0737505c311123a70dbde), // 1e2068 * 2**-6614
+    (0xad9175257b5c818a8d57c911af0068bd, 0xb237eedf215bd6d494376a97a8989958), // 1e2095 * 2**-6704
+    (0x8c350fab651a6b5fe5242d38dc1e6a58, 0xbf151b29ee89d168fb2f7ea48125ea0d), // 1e2122 * 2**-6794
+    (0xe2845e2dbb9d61b154524c2a034d7c2e, 0x4bdab9976ddbd2857ea17657e6c0bb81), // 1e2149 * 2**-6883
+    (0xb6faa16ac604d6f6180f7fcdf9f88b9d, 0x006deaf7b163aa516ae82736619c70a5), // 1e2176 * 2**-6973
+    (0x93cf2cf479d9f2c171a4f558fd9ea65c, 0x9eb1ecd864adab32429ded11bd92a19c), // 1e2203 * 2**-7063
+    (0xeecc7c3a5714c80775d6bcab6fdd41b5, 0x37b19fb2ab1429e38f7d0757d681d9ce), // 1e2230 * 2**-7152
+    (0xc0e66da6f29a1823b68a22a186bd6930, 0xd3aefdc15b52c836f06023d246a4b307), // 1e2257 * 2**-7242
+    (0x9bd2ced41ce91e5af2e55a1e75abcdc6, 0xc49ca8111f28a36c5a2b4d09ddb4b9da), // 1e2284 * 2**-7332
+    (0xfbbf13778969e4ff1dfd5f7d8f30f12f, 0x904e03f2d13d78228e44f3803040cbc2), // 1e2311 * 2**-7421
+    (0xcb5beef5a66a8f9f8fd39501fa9cb03e, 0xee310ebae0b773087a0bc4d063e2d9b2), // 1e2338 * 2**-7511
+    (0xa445ade8401e6ccca0e037f2d54a2669, 0x98abe718b86f986a8ba7bc400b0b2bbd), // 1e2365 * 2**-7601
+    (0x84b2b10e5a2c8a1c1a3fe5bc78c86cfc, 0x601078098b0ee28d245fad5cb6054db2), // 1e2392 * 2**-7691
+    (0xd6629cc0f33889fe2fe14c6f3ad8e957, 0xd0ea74eff3dc19071b03069690b847a8), // 1e2419 * 2**-7780
+    (0xad2dd2381bbedc9d7340623032e0ddff, 0x72a6dd428475ed580d4673e43bf4806b), // 1e2446 * 2**-7870
+    (0x8be49356785f505028100ee6488427c6, 0x0cf174ec94836ef6ec628bbe71fb2d79), // 1e2473 * 2**-7960
+    (0xe2025615dbea8de3604814d6fa77ea36, 0x4a74499db323bb89d569897f60848382), // 1e2500 * 2**-8049
+    (0xb69197826e43fc20f0aecfbe7849933f, 0x18368aea5eae7fb3115815fa7d471933), // 1e2527 * 2**-8139
+    (0x937a5375eb11b6b95c40e21232336376, 0x3d3627ea0334a5a8aee779ad27115f57), // 1e2554 * 2**-8229
+    (0xee436742d51c99ee52c3a800079ecb2b, 0x58f1b71c1890df5c23f383161ecc3e24), // 1e2581 * 2**-8318
+    (0xc077b1c77fa526c9d1b8dbcb7efb1a18, 0x181091411f3ab863e9403d810b56b1e6), // 1e2608 * 2**-8408
+    (0x9b795b9967fdd2b8d8c208a40e4aeda8, 0x7786c5f036cca4b0d209269dd28238d9), // 1e2635 * 2**-8498
+    (0xfb2e8fc4447cfe0aef1014f475126167, 0xf312a0f14f1f275e6d93318e81a0cfe9), // 1e2662 * 2**-8587
+    (0xcae7321225ad427c2cc6160ebfa2c70f, 0xb63fde47174d6bdcf00443ea8f06ca63), // 1e2689 * 2**-8677
+    (0xa3e761161e63d4643c85a6192ebf4818, 0x2dc5fa5ab83b071db0f8d5b86debb342), // 1e2716 * 2**-8767
+    (0x846684419cc6a29f61e0206bac014dea, 0xe7225cc4f16792ca4e60902f3a2b0d8d), // 1e2743 * 2**-8857
+    (0xd5e78b8333e07f603db2ff4bb59321f5, 0x7420d5644001f329066359a24d221f4e), // 1e2770 * 2**-8946
+    (0xacca687cedf8cf1f46cbe1bb4f2f6114, 0xd481ff8fdde4b82621470d3242e0ec95), // 1e2797 * 2**-9036
+    (0x8b9445356a892e18c9c91c63974bde54, 0x0145b8241dc4fb6b1a9ffb0fb3f5f2cc), // 1e2824 * 2**-9126
+    (0xe18098a2f0e018a477172c7a96d790c6, 0x9888cb939a027df3de8e9083e0015ce0), // 1e2851 * 2**-9215
+    (0xb628c9e62dee69dfc42e89468b3f85e6, 0xbee2994c0b0f7ae8ac5e02a279d2eee3), // 1e2878 * 2**-9305
+    (0x9325aaac89304b577a63589863efd480, 0x202d2f87585ec0d73096595b25be205f), // 1e2905 * 2**-9395
+    (0xedbaa0fc5d36b5f2ae4e957973a61421, 0x5775ccf03cadd050b21e4d12bce23eb0), // 1e2932 * 2**-9484
+    (0xc0093579152f1637c6c6cad4155d177b, 0xb855917f2e161fafc4b9321923f61d2e), // 1e2959 * 2**-9574
+    (0x9b201bb7f3764c092b4fde1e5259d769, 0x9c887e11bedeb95d207c4b509a69d1cf), // 1e2986 * 2**-9664
+    (0xfa9e5f064c2a8357531f1fb828795903, 0x603996e5b280b2d519d3907060342733), // 1e3013 * 2**-9753
+    (0xca72b831ff7bef2db5ceaf53c9875f4b, 0x65d2c975f1b0b0165700a0eb8653e8aa), // 1e3040 * 2**-9843
+    (0xa3894a65f8a80102dc922d41361fd751, 0xa23bd82a48630c3124aaf8cb25e7cb4a), // 1e3067 * 2**-9933
+    (0x841a832f435fff446b21539ca68627a8, 0xcfea649c6e4e89be26e0de9169dc244c), // 1e3094 * 2**-10023
+    (0xd56cc0eaf7f9bf2694fc91dc9abc7ba1, 0x290541016b694c60db552c362b878553), // 1e3121 * 2**-10112
+    (0xac6737d31cb8a155b3f890ed7202be0d, 0xa067936208dc30b4c48eccf7022523e6), // 1e3148 * 2**-10202
+    (0x8b44252db5d4beccb572c3ad6f625022, 0x579db85ec9520d96b410482e4c8617d9), // 1e3175 * 2**-10292
+    (0xe0ff25aa2109f002a5ee80ee33b6d3e3, 0x5391d02d55e64c820f83ac078de0c1b1), // 1e3202 * 2**-10381
+    (0xb5c0387367f6548fd2f9a57303a76cad, 0xac60c143537a391ec436a055e4042f4a), // 1e3229 * 2**-10471
+    (0x92d1327c5e4eb1acb7f3bc7bfa6bf99f, 0xbdc15fc57d902984180a187e335feaf1), // 1e3256 * 2**-10561
+    (0xed322939c32c1801240421cc6acc3893, 0x456509d69d08d96ccc0f55ea864dd7f6), // 1e3283 * 2**-10650
+    (0xbf9af89735b84ec6340953adfa694d5d, 0x2d98d9421c9172d4403eeb057935fdab), // 1e3310 * 2**-10740
+    (0x9ac70f124551f249c7e70c85385f1703, 0xea45ceaf308e17bae828ed835a30e034), // 1e3337 * 2**-10830
+    (0xfa0e810e01390bcfb90da323856bede6, 0xf2d9eae10180e7d5f011494d69bb8602), // 1e3364 * 2**-10919
+    (0xc9fe812ebbd87f3f8dae21bcd6b25210, 0x1424c4c072c2e18dd5a88c886e64b1f4), // 1e3391 * 2**-11009
+    (0xa32b69b8bbc5d312f69430fe495e98c1, 0xd5a18d0d28e064681260965cb8eff42a), // 1e3418 * 2**-11099
+    (0x83ceadbe33db31a58b9753536833ce9f, 0x2a8347cd3bf577a2fa0e6fd4c04ba43a), // 1e3445 * 2**-11189
+    (0xd4f23ccfb1916df5cbdcd02f23cc7690, 0x4949d09424b876265adbd55696e1cdda), // 1e3472 * 2**-11278
+    (0xac044019e585a98a452f2663308a3960, 0xde0174a26fefe9706316b5f8f0d81e02), // 1e3499 * 2**-11368
+    (0x8af43324e3b8626e4019fe9af2c812dd, 0xd429630f6da422aa65f5cac21dd72c88), // 1e3526 * 2**-11458
+    (0xe07dfd00ab8cff8d9a1e91514baacf6b, 0x88724451d8f5efd9264b30e808aff884), // 1e3553 * 2**-11547
+    (0xb557e307932c9b9efe7f083d3db29872, 0x4eacc31639675571855d830dd92d122c), // 1e3580 * 2**-11637
+    (0x927ceac98492e51f840757fc7ed490c0, 0x8809df7484c78a3115de99278ee9fdec), // 1e3607 * 2**-11727
+    (0xeca9ffcdf4b42570746964b0fa10a803, 0x8b4b3445f7def45051205891923c41de), // 1e3634 * 2**-11816
+    (0xbf2cfafd78b3b02ef1aff3f5b8e1f87f, 0x4c87dd03fe6c5fb2da81df941cb3490f), // 1e3661 * 2**-11906
+    (0x9a6e358af47bf184281f5ee0edf6732c, 0xa4da23538baaada2d659aac5bc001536), // 1e3688 * 2**-11996
+    (0xf97ef5abdfc586b7d6bf4887593cf9e2, 0xb10e1d8d03c8bf0b07512603a7438167), // 1e3715 * 2**-12085
+    (0xc98a8ce1f8da1415adda65275318f02c, 0x27ab1718db124b2d1e18220e8a1df9f3), // 1e3742 * 2**-12175
+    (0xa2cdbeef666ecd1bde9be42caad30c41, 0xe16ac2f35302dee319cc72a1e03e64bb), // 1e3769 * 2**-12265
+    (0x838303d56283b0fc8e0a7a67c5dd82c4, 0x4fb825adc287535c59ee532995acce3c), // 1e3796 * 2**-12355
+    (0xd477ff08e9fc6c78f3f09dc9df46cd77, 0x176f4c6bf1951f33a3acc8af5bf6aa59), // 1e3823 * 2**-12444
+    (0xaba1813098b57a3a89844a21c7b6521e, 0xd2a7f07d20f10adaaa3f64c9498d2ded), // 1e3850 * 2**-12534
+    (0x8aa46f008cdb617f7ac8fe6e1db8a3df, 0x8f7d6c78dd676ddf1e5a7d89999ab229), // 1e3877 * 2**-12624
+    (0xdffd1e7be8191190afb619b59ab7cab9, 0xb9e4b3d49fb1154792c657741ea2902e), // 1e3904 * 2**-12713
+    (0xb4efc9803a35618e34986fd429e0ed4e, 0x3a080d99f35053195ddd287d6001b2b1), // 1e3931 * 2**-12803
+    (0x9228d3782626a4abec5afd069d580df9, 0x3113bb7be5a5ec064c134ca13aaf895a), // 1e3958 * 2**-12893
+    (0xec22248bf965ca3d8ed4a06e4e75089d, 0x2518dbdddcf0324b644fd54c43128acb), // 1e3985 * 2**-12982
+    (0xbebf3c878a7a8b3d3d4e3aacee5a632b, 0x9e3eadb9e75765993004eda2a827a4b6), // 1e4012 * 2**-13072
+    (0x9a158f04a8c183296a24bfad0ca1a322, 0x2c0cc380bdbba12d534b8fb9a274ff52), // 1e4039 * 2**-13162
+    (0xf8efbcb07f338a4941622240c3dac580, 0x570ab1c784b388abbbba3abb257853ad), // 1e4066 * 2**-13251
+    (0xc916db256aa059b50778eac0004c1304, 0x8078f572f61291d2ce3224a524cf045b), // 1e4093 * 2**-13341
+    (0xa27049eb0920d64a936d04cea460945b, 0x5d989a07791199a59310a1515c508f6b), // 1e4120 * 2**-13431
+    (0x8337855bd20594884f52e42a4f427b02, 0x7fd573142ed6eb6ddf1223cd904dcc37), // 1e4147 * 2**-13521
+    (0xd3fe076e41c9fa357f931391840a9990, 0xe1576add9fd6a910c24b5c3a8d728699), // 1e4174 * 2**-13610
+    (0xab3efaf69961167e3c17645935ae7389, 0x0e1eeca80fa9541f93e49a9bd8127a91), // 1e4201 * 2**-13700
+    (0x8a54d8a6590d3496e9cc6e8725ec5d92, 0x15a73001c7a90d9fdda47f7350d7197a), // 1e4228 * 2**-13790
+    (0xdf7c89f146dab8680d8f84a324b0de94, 0x46bf18412096f7ad636722c37429f012), // 1e4255 * 2**-13879
+    (0xb487ebbafb7caa8011be2be1c9231e6f, 0xc26f6185fc9b7ee28cbfad4d0cba563c), // 1e4282 * 2**-13969
+    (0x91d4ec6c7d2e416dc1be3bb913882bbe, 0x2d05a8c5f0fe0b82bbef751d117ac8b6), // 1e4309 * 2**-14059
+    (0xeb9a9746f2a89ed32aff49d1db06aa69, 0x551f826a883dc38e7b472f6c5b6d7882), // 1e4336 * 2**-14148
+    (0xbe51bd112c40a264fe7f27c689ab915c, 0xed6268296b8d90c3f3c81b68139740f6), // 1e4363 * 2**-14238
+    (0x99bd1b621ac83d01cc5b42ba7927e7c4, 0x2b37dba70bd40dadfc123f1eec7e09d3), // 1e4390 * 2**-14328
+    (0xf860d5ec921dab533161deec6ef5ef3c, 0x367ea89e3b0adf0faedbf750596c1297), // 1e4417 * 2**-14417
+    (0xc8a36bd2db46e0d2cd3d5b69c56f10fa, 0x943fd990eeabe7953a079ec61f7c66dd), // 1e4444 * 2**-14507
+    (0xa2130a8cc61c02db6364dfbcff5b5db7, 0x8e0ef14998059bdfc4643ffdc0c5a8af), // 1e4471 * 2**-14597
+    (0x82ec3238936552b1f62a12b2fa5ebdaf, 0x7e1c3fda6a47eac3530478a162eaef8b), // 1e4498 * 2**-14687
+    (0xd38455d770b6600b11b181ee47d7436b, 0xb753f5a344cfa83e2b0cca68f2749318), // 1e4525 * 2**-14776
+    (0xaadcad4b5d5a2c9fdbd1aba26692e3f4, 0x177aed6af9c2d01fb0f687dfb30adb5b), // 1e4552 * 2**-14866
+    (0x8a056ffbff3cd0f5bfdc6addc4ad32d8, 0x2fc9d74294aeca1991a71318a4e07915), // 1e4579 * 2**-14956
+    (0xdefc3f36506d3fec27a13e6894009876, 0x3619618ed472273648c1bac219999166), // 1e4606 * 2**-15045
+    (0xb4204995892b014ff7cd83cb723cb939, 0xcdd9ea9d01fb639cfab1b6440ba48e6e), // 1e4633 * 2**-15135
+    (0x9181358ad3bf7271bc28502ac02f02f3, 0x48cc084e2863178a6ac8dd97ff91e929), // 1e4660 * 2**-15225
+    (0xeb1357d21ba616580a8da18e1c35b584, 0xcf4f81ac2ba25b9511d25874a6f3f457), // 1e4687 * 2**-15314
+    (0xbde47c763408313d2e3be2e5e3361d50, 0x70eadb002d2ec6cfda2948ca27128784), // 1e4714 * 2**-15404
+    (0x9964da86140465ac68e5f70024734bc2, 0x466dc7e42d443f45ceae77dd9fdabec7), // 1e4741 * 2**-15494
+    (0xf7d24130e645ddd7462a2bf67ddfa64b, 0x39e35541a56f6aee715290260f98c806), // 1e4768 * 2**-15583
+    (0xc8303ec42ad8802678c6a18da2580215, 0x4e1c7e58586d7eebc45bd180f52795fe), // 1e4795 * 2**-15673
+    (0xa1b600b5d15862610a3a0afe82eefc53, 0xe13a29ba994d78335cb9d7da226ad5c2), // 1e4822 * 2**-15763
+    (0x82a10a52c5f784ef0c223ba4e5d48dc8, 0xb3833fff33952a75c75a7730e1f2f537), // 1e4849 * 2**-15853
+    (0xd30aea1c459da25ad0d19aa98b178248, 0x1c3455ea9e895112739c6bcf7de99bd6), // 1e4876 * 2**-15942
+    (0xaa7a980e6d2056e424d45c13d44d776d, 0x2d8c600d5c7081d8cf311d10214424da), // 1e4903 * 2**-16032
+    (0x89b634e7456ffa1cbd8286572262da4b, 0x5e13a71fe5a0dfdfa4870a5d6ff47bd5), // 1e4930 * 2**-16122
+    (0xde7c3e20a5cca6ef030ed162bfdecb01, 0x4325a88c69645e5c2ec9dd22ffc2c4b5), // 1e4957 * 2**-16211
+];
+
+// The entries in between are 10**p = 10**q * 5**r * 2**r for q = p - r, so
+// they are computed by multiplying the mantissa for 10**q by the exact 5**r
+// and rounding up, like the small table for f64. That differs from the exact
+// mantissa by at most 2, which OFFSETS corrects for.
+const STEP: usize = 27;
+
+// POW5[r] is 5**r.
+static POW5: [u64; STEP] = {
+    let mut pow5 = [1u64; STEP];
+    let mut r = 1;
+    while r < pow5.len() {
+        pow5[r] = 5 * pow5[r - 1];
+        r += 1;
+    }
+    pow5
+};
+
+// OFFSETS packs 2 bits per power of 10 from 1e{MIN} to 1e{MAX}: the amount
+// by which mul_pow5 overestimates it, as recomputed by tests::offsets.
+#[rustfmt::skip]
+static OFFSETS: [u64; 309] = [
+    0x0401155010505454, 0x4515400400150500, 0x4414541145445451, 0x0000000000514014,
+    0x4000040000110004, 0x5155559555555240, 0x5400596556aa6995, 0x1455550004404501,
+    0x595a655544515405, 0x0000140004016556, 0x1555554555555440, 0x4044450100154504,
+    0x5515400101400511, 0x0150455455555554, 0x1400000000155154, 0x0000440005104000,
+    0x0055401451155550, 0x1541114040054004, 0x0515104411555404, 0x5005144001454450,
+    0x1451415411541500, 0x4554554551154105, 0x4540545551055155, 0x5545541555515554,
+    0x4555565511515505, 0x0000000000055145, 0x0000000000000000, 0x1511155511511514,
+    0x0044400144140104, 0x1044004050045051, 0x4000404000004510, 0x4401000001010110,
+    0x4110400000000000, 0x4150440054115455, 0x0000000000000000, 0x4000000400000000,
+    0x5551550151000000, 0x1554150555445445, 0x5941140145500154, 0x01004155965a5956,
+    0x1551514400010000, 0x4014450501145555, 0x4155515411554141, 0x5404014415110001,
+    0x4104115515145545, 0x1151140504440051, 0x0504004001451511, 0x0010554405505400,
+    0x0040140441000405, 0x1000000000411400, 0x0450000010000001, 0x4551541100054000,
+    0x5655551551455405, 0x0000000000000150, 0x0000051515000100, 0x4144005105001040,
+    0x0010000005404504, 0x5154404550410000, 0x1101155444101441, 0x0055555041055545,
+    0x1054000000400040, 0x1400401555105155, 0x5515551541044010, 0x0000000010015555,
+    0x0454550504145400, 0x5545155105451555, 0x0000015455455115, 0x0041000040404144,
+    0x5559155659001000, 0x10041400400405a5, 0x5400004404400101, 0x4515155155544545,
+    0x4054014445055545, 0x1514500100054550, 0x1400515000004005, 0x0545115550045401,
+    0x6550140040114110, 0x0551445655959559, 0x5440500115551045, 0x5100000000001414,
+    0x1101450054550001, 0x5900000000000000, 0x4044055455595551, 0x1040010000400514,
+    0x5554555554041004, 0x0105000105541555, 0x5155455595514540, 0x0100915555555155,
+    0x5114151040004000, 0x0141041041555114, 0x4405010544410015, 0x0000000000000050,
+    0x0101455545191451, 0x0010000000100011, 0x0500010000040140, 0x5555655545000500,
+    0x1055541554054514, 0x0000000010000001, 0x0000000000000000, 0x1411000145040010,
+    0x5555555950010405, 0x5655559655545555, 0x4554451414551016, 0x0551510505555515,
+    0x59a5944000441110, 0x0100000415596599, 0x0000001000000000, 0x0001400000000000,
+    0x1005155555555444, 0x1550411000050400, 0x1401000115515555, 0x0145114550555004,
+    0x4000501001111541, 0x0000051400401004, 0x5510104000104404, 0x4510001445556555,
+    0x1555555005554110, 0x5454515955511145, 0x0115110005145451, 0x0105551551550515,
+    0x0105501400004404, 0x0055005400010101, 0x1045005011155554, 0x1040100000000411,
+    0x0041100140514101, 0x0000000000000000, 0x55569a4000000000, 0x4554554551559559,
+    0x0000001140001045, 0x0041501051150410, 0x4500040054144414, 0x0000005145555455,
+    0x5544151100000000, 0x0554515115411551, 0x5915044145540104, 0x5505405150411440,
+    0x5555454510515554, 0x4a55554145545559, 0x0004404000556596, 0x1040000001000000,
+    0x4115555565554540, 0x0000504154515450, 0x5405010400050000, 0x1540000404010415,
+    0x4000050014005444, 0x15595555565a5400, 0x5400400500054515, 0x5555515511505515,
+    0x5000014055551555, 0x0040000044101150, 0x0100000140000014, 0x5054145150155400,
+    0x1545055455051515, 0x0000000555555554, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1400000000040000, 0x5655144151455545, 0x0000000014555564,
+    0x0104550540000000, 0x5155659545641004, 0x51155565955a5514, 0x1004054551554545,
+    0x0515145100000400, 0x5141041144541551, 0x0000010000401105, 0x0001400540114500,
+    0x5544145100044151, 0x5151151545410445, 0x0400010405155545, 0x0000044440001411,
+    0x0000500050041044, 0x4505545000400400, 0x4145001015144114, 0x5505511451440455,
+    0x0000000000104514, 0x4000000104100004, 0x0004110011011510, 0x4151000000010044,
+    0x5455950514155405, 0x5054400501051455, 0x1044150011101114, 0x4000000000000010,
+    0x0001159559699555, 0x5115000001000100, 0x5565529510514510, 0x054010544105565a,
+    0x5544555154441045, 0x0401414400104404, 0x0050015541555554, 0x5555649100000404,
+    0x4401450044554555, 0x4454411550511154, 0x5145555505555144, 0x1040456552595555,
+    0x0000000515554554, 0x4954555540000000, 0x0100545041016552, 0x1441050100010040,
+    0x0005a65555555255, 0x0000014101000100, 0x4000000000000000, 0x4401154504000000,
+    0x5545155555144051, 0x5401001001000101, 0x5400154555664965, 0x0000000400550014,
+    0x5155555501011000, 0x5451104514405410, 0x0455411545414115, 0x0101000110004440,
+    0x4451040400045100, 0x4550455515101515, 0x4445050144041540, 0x5555551455455001,
+    0x0000000000000500, 0x1511041154000044, 0x4000000001055545, 0x0111400414414000,
+    0x4411555544400440, 0x4115554441051445, 0x0040455511555551, 0x0100400004504141,
+    0x4545545140001040, 0x0000000000015555, 0x0000000000000000, 0x5244154514544551,
+    0x4041415595555556, 0x5555514440400114, 0x5555559559155555, 0x4404111001504496,
+    0x0051555550554410, 0x0414000004040004, 0x5515144545045455, 0x5555596591455414,
+    0x0151114414509659, 0x4404000100000014, 0x5551455441556555, 0x0000004525565555,
+    0x0550151500000000, 0xa559695565451045, 0x5155554555510155, 0x0004004145114100,
+    0x4000000000040044, 0x1000000000001054, 0x0051500550504104, 0x0105004055000545,
+    0x0000001040000400, 0x1000400000554040, 0x0510100004000041, 0x0155055045001400,
+    0x5955595554910501, 0x005415541000105a, 0x1545055000554500, 0x1001411455515444,
+    0x4010000051505405, 0x5645155559000001, 0x4040001440000415, 0x5400001000000001,
+    0x9a55145555555515, 0x1000000996596996, 0x5555514410401000, 0x9565a6565a541555,
+    0x0445505141450516, 0x6550041540450040, 0x4400009565955155, 0x4504410005500044,
+    0x0555155551441450, 0x1051411410455114, 0x4404000011140500, 0x1150054051444040,
+    0x5450104145545115, 0x1555555154415145, 0x5515549455551041, 0x1155415445555005,
+    0x5514140401504104, 0x4015101655256555, 0x0111405140101050, 0x5554555555515054,
+    0x1500114014011455, 0x1105041551555595, 0x1444000000400040, 0x0000000050001505,
+    0x9695645495000000, 0x5456595564554555, 0x0040040140400041, 0x1040000000000000,
+    0x0540104401414400, 0x4424555550455545, 0x0440144141409155, 0x0041140015000011,
+    0x1000000000000000, 0x0415500000000000, 0x0405511015550540, 0x1555550111441010,
+    0x4000154154441140, 0x5100004104000400, 0x5456496591554525, 0x0110440165514559,
+    0x1401044010054000, 0x5155055455150040, 0x5041404410544005, 0x4554514104155055,
+    0x4101100155551445, 0x5550541100000000, 0x4441450504514545, 0x1555659652555454,
+    0x0000000000005555,
+];
+
+// mul_pow5 returns ⌈x * 5**r⌉ as hi, lo halves, normalized so its high bit
+// is set, where x is the 256-bit mantissa with halves hi, lo.
+fn mul_pow5((hi, lo): (u128, u128), r: usize) -> (u128, u128) {
+    if r == 0 {
+        return (hi, lo);
+    }
+    // The 320-bit product is the 64-bit limbs l4..l0.
+    let m = u128::from(POW5[r]);
+    let c0 = u128::from(lo as u64) * m;
+    let c1 = (lo >> 64) * m + (c0 >> 64);
+    let c2 = u128::from(hi as u64) * m + (c1 >> 64);
+    let c3 = (hi >> 64) * m + (c2 >> 64);
+    let (l4, l3, l2, l1, l0) = (c3 >> 64, c3 as u64, c2 as u64, c1 as u64, c0 as u64);
+
+    // Shift the high bit of l4 to the top and keep 256 bits, rounding up.
+    let mut top = (l4 << 64) | u128::from(l3);
+    let mut bot = (u128::from(l2) << 64) | u128::from(l1);
+    let mut rest = l0;
+    let s = (l4 as u64).leading_zeros();
+    if s > 0 {
+        top = (top << s) | (bot >> (128 - s));
+        bot = (bot << s) | u128::from(rest >> (64 - s));
+        rest <<= s;
+    }
+    let (bot, carry) = bot.overflowing_add(u128::from(rest != 0));
+    (top + u128::from(carry), bot)
+}
+
+// get returns the 256-bit mantissa ⌈10**p * 2**k⌉ with its high bit set, in
+// pmHiLo form: hi<<128 - lo.
+pub fn get(p: isize) -> (u128, u128) {
+    let i = (p - MIN) as usize;
+    let offset = (OFFSETS[i / 32] >> (i % 32 * 2)) & 3;
+    let (hi, lo) = mul_pow5(TAB[i / STEP], i % STEP);
+    let (lo, borrow) = lo.overflowing_sub(u128::from(offset));
+    let hi = hi - u128::from(borrow);
+    (hi + u128::from(lo != 0), lo.wrapping_neg())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX, MIN, STEP, TAB, get};
+    use num_bigint::BigUint;
+    use std::vec::Vec;
+
+    // pow10 computes ⌈10**p * 2**k⌉ with its high bit set, along with k.
+    fn pow10(p: isize) -> (BigUint, isize) {
+        let k = 255 - crate::binary128::log2_pow10(p);
+        let one = BigUint::from(1u32);
+        let ten = BigUint::from(10u32);
+        let num = ten.pow(p.max(0) as u32) << k.max(0);
+        let den = ten.pow((-p).max(0) as u32) << (-k).max(0);
+        let x = (num + &den - one) / den;
+        assert_eq!(x.bits(), 256, "1e{p} * 2**{k} is not normalized");
+        (x, k)
+    }
+
+    fn halves(x: &BigUint) -> (u128, u128) {
+        let digits = x.to_u64_digits();
+        let half = |i: usize| u128::from(digits[i + 1]) << 64 | u128::from(digits[i]);
+        (half(2), half(0))
+    }
+
+    #[test]
+    fn table() {
+        assert_eq!(TAB.len(), (MAX - MIN) as usize / STEP + 1);
+        let comments: Vec<&str> = include_str!("pow10.rs")
+            .lines()
+            .filter_map(|line| {
+                let entry = line.trim_start().strip_prefix("(0x")?;
+                Some(entry.split_once("// ")?.1)
+            })
+            .collect();
+        assert_eq!(comments.len(), TAB.len());
+        for ((i, &entry), comment) in TAB.iter().enumerate().zip(comments) {
+            let p = MIN + (i * STEP) as isize;
+            let (x, k) = pow10(p);
+            let (hi, lo) = halves(&x);
+            assert!(
+                entry == (hi, lo),
+                "TAB entry for 1e{p} should be ({hi:#034x}, {lo:#034x})",
+            );
+            assert_eq!(comment, std::format!("1e{p} * 2**{k}"));
+        }
+    }
+
+    #[test]
+    fn offsets() {
+        for p in MIN..=MAX {
+            let (x, _k) = pow10(p);
+            let (hi, lo) = halves(&x);
+            let pm = (hi + u128::from(lo != 0), lo.wrapping_neg());
+            assert!(get(p) == pm, "OFFSETS is wrong for 1e{p}");
+        }
+    }
+}
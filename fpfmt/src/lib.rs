@@ -1,18 +1,28 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(mixed_script_confusables)]
 #![allow(
     clippy::cast_possible_truncation,
     clippy::cast_possible_wrap,
     clippy::cast_sign_loss,
     clippy::many_single_char_names,
+    clippy::missing_panics_doc,
     clippy::must_use_candidate,
     clippy::unreadable_literal
 )]
 
-use std::fmt::{self, Display};
-use std::ops::BitOr;
+#[cfg(test)]
+extern crate std;
 
+use core::fmt::{self, Display, LowerExp, Write as _};
+use core::ops::{Add, BitOr, Shl, Sub};
+
+pub mod binary128;
 mod pow10;
 
+// SMALL_TABLE selects the small power-of-ten table for every entry point
+// other than small::Buffer, which always uses it.
+const SMALL_TABLE: bool = cfg!(feature = "small-table");
+
 // bool2 converts b to an integer: 1 for true, 0 for false.
 fn bool2<T>(b: bool) -> T
 where
@@ -38,6 +48,27 @@ fn unpack64(f: f64) -> (u64, isize) {
     (m, (e - 1) + MIN_EXP)
 }
 
+// unpack16 returns m, e such that the 16-bit float with bits b is m * 2**e,
+// for a format with mbits explicit mantissa bits: 10 for binary16 and 7 for
+// bfloat16. It also returns the exponent of the smallest normal value.
+// The caller is expected to have handled 0, NaN, and ±Inf already.
+fn unpack16(b: u16, mbits: isize) -> (u64, isize, isize) {
+    let shift = 64 - (mbits + 1);
+    let ebits = 15 - mbits;
+    let bias = (1 << (ebits - 1)) - 1;
+    let min_exp = -(bias - 1 + mbits + shift);
+    let b = u64::from(b);
+    let mut m = (1 << 63) | ((b & ((1 << mbits) - 1)) << shift);
+    let mut e = ((b >> mbits) & ((1 << ebits) - 1)) as isize;
+    if e == 0 {
+        m &= !(1 << 63);
+        e = min_exp;
+        let s = m.leading_zeros();
+        return (m << s, e - s as isize, min_exp);
+    }
+    (m, (e - 1) + min_exp, min_exp)
+}
+
 // An unrounded represents an unrounded value.
 #[derive(Copy, Clone)]
 struct Unrounded(u64);
@@ -94,7 +125,6 @@ impl Unrounded {
         Unrounded(self.0.wrapping_add(δ as u64))
     }
 
-    #[expect(dead_code)]
     fn div(self, d: u64) -> Unrounded {
         let x = self.0;
         Unrounded(x / d) | Unrounded(self.0 & 1) | bool2::<Unrounded>(!x.is_multiple_of(d))
@@ -131,31 +161,88 @@ static U64_POW10: [u64; 20] = {
     u64_pow10
 };
 
+// Fixed returns the n-digit decimal form of f as d, p
+// such that d * 10**p ≈ f. It assumes that n <= 18.
+// The caller is expected to have handled 0 already.
+fn fixed<const SMALL: bool>(f: f64, n: usize) -> (u64, isize) {
+    let (m, e) = unpack64(f);
+    let mut p = n as isize - 1 - log10_pow2(e + 63);
+    let u = uscale(m, prescale::<SMALL>(e, p, log2_pow10(p)));
+    let mut d = u.round();
+    if d >= U64_POW10[n] {
+        d = u.div(10).round();
+        p -= 1;
+    }
+    (d, -p)
+}
+
 // Short computes the shortest formatting of f,
 // using as few digits as possible that will still round trip
 // back to the original f64.
-fn short(f: f64) -> (u64, isize) {
-    const MIN_EXP: isize = -1085;
-
+fn short<const SMALL: bool>(f: f64) -> (u64, isize) {
     let (m, e) = unpack64(f);
+    short_unpacked::<SMALL>(m, e, 11, -1085)
+}
 
+// A Mantissa is a left-justified mantissa as passed to interval: u64 for
+// f64 and the 16-bit formats, u128 for binary128, with the logarithm
+// approximations that are exact for that format's exponent range.
+trait Mantissa:
+    Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Shl<isize, Output = Self>
+{
+    const ONE: Self;
+    const BITS: isize;
+    fn low_bit(self, z: isize) -> isize;
+    fn log10_pow2(x: isize) -> isize;
+    fn skewed(e: isize) -> isize;
+}
+
+impl Mantissa for u64 {
+    const ONE: Self = 1;
+    const BITS: isize = 64;
+    fn low_bit(self, z: isize) -> isize {
+        (self >> z) as isize & 1
+    }
+    fn log10_pow2(x: isize) -> isize {
+        log10_pow2(x)
+    }
+    fn skewed(e: isize) -> isize {
+        skewed(e)
+    }
+}
+
+// interval returns the rounding interval of m * 2**e as unpacked from
+// a binary format whose normal values have z0 extra zero bits at the bottom
+// of m and whose smallest normal value has exponent minExp. Its bounds are
+// min * 2**e and max * 2**e, inclusive if m is even, as reported by odd, and
+// p is chosen so that the interval scaled by 10**p is 1 to 10 units wide.
+#[inline]
+fn interval<M: Mantissa>(m: M, e: isize, z0: isize, min_exp: isize) -> (isize, M, M, isize) {
     let p;
-    let min: u64;
-    let mut z = 11; // extra zero bits at bottom of m; 11 for 53-bit m
-    if m == 1 << 63 && e > MIN_EXP {
-        p = -skewed(e + z);
-        min = m - (1 << (z - 2)); // min = m - 1/4 * 2**(e+z)
+    let min: M;
+    let mut z = z0; // extra zero bits at bottom of m; 11 for 53-bit m in a u64
+    if m == M::ONE << (M::BITS - 1) && e > min_exp {
+        p = -M::skewed(e + z);
+        min = m - (M::ONE << (z - 2)); // min = m - 1/4 * 2**(e+z)
     } else {
-        if e < MIN_EXP {
-            z = 11 + (MIN_EXP - e);
+        if e < min_exp {
+            z = z0 + (min_exp - e);
         }
-        p = -log10_pow2(e + z);
-        min = m - (1 << (z - 1)); // min = m - 1/2 * 2**(e+z)
+        p = -M::log10_pow2(e + z);
+        min = m - (M::ONE << (z - 1)); // min = m - 1/2 * 2**(e+z)
     }
-    let max = m + (1 << (z - 1)); // max = m + 1/2 * 2**(e+z)
-    let odd = (m >> z) as isize & 1;
+    let max = m + (M::ONE << (z - 1)); // max = m + 1/2 * 2**(e+z)
+    let odd = m.low_bit(z);
+    (p, min, max, odd)
+}
 
-    let pre = prescale(e, p, log2_pow10(p));
+// shortUnpacked is short for m * 2**e as unpacked from a binary format
+// described by z0 and minExp as for interval.
+#[inline]
+fn short_unpacked<const SMALL: bool>(m: u64, e: isize, z0: isize, min_exp: isize) -> (u64, isize) {
+    let (p, min, max, odd) = interval(m, e, z0, min_exp);
+
+    let pre = prescale::<SMALL>(e, p, log2_pow10(p));
     let dmin = uscale(min, pre).nudge(odd).ceil();
     let dmax = uscale(max, pre).nudge(-odd).floor();
 
@@ -170,6 +257,48 @@ fn short(f: f64) -> (u64, isize) {
     (d, -p)
 }
 
+// to_decimal returns the shortest d, p such that d * 10**p round trips
+// back to |f|, with trailing zeros removed from d. It returns 0, 0 for ±0.
+// The caller must pass a finite f.
+pub fn to_decimal(f: f64) -> (u64, i32) {
+    if f == 0.0 {
+        return (0, 0);
+    }
+    let (d, p) = short::<SMALL_TABLE>(f);
+    let (d, p) = trim_zeros(d, p);
+    (d, p as i32)
+}
+
+// Directed computes the shortest formatting of f that still round trips
+// back to f, like short, but only considers decimals that are >= |f| if up
+// is set, or <= |f| otherwise. Among the shortest of those, it returns the
+// one closest to |f|.
+fn directed<const SMALL: bool>(f: f64, up: bool) -> (u64, isize) {
+    let (m, e) = unpack64(f);
+    let (mut p, min, max, odd) = interval(m, e, 11, -1085);
+
+    // Only half of the rounding interval is usable, which may contain no
+    // p-digit decimal. It always contains a (p+1)-digit one.
+    let (dmin, dmax) = loop {
+        let pre = prescale::<SMALL>(e, p, log2_pow10(p));
+        let (dmin, dmax) = if up {
+            (uscale(m, pre).ceil(), uscale(max, pre).nudge(-odd).floor())
+        } else {
+            (uscale(min, pre).nudge(odd).ceil(), uscale(m, pre).floor())
+        };
+        if dmin <= dmax {
+            break (dmin, dmax);
+        }
+        p += 1;
+    };
+
+    let d = dmax / 10;
+    if d * 10 >= dmin {
+        return trim_zeros(d, -(p - 1));
+    }
+    (if up { dmin } else { dmax }, -p)
+}
+
 // skewed computes the skewed footprint of m * 2**e,
 // which is ⌊log₁₀ 3/4 * 2**e⌋ = ⌊e*(log₁₀ 2)-(log₁₀ 4/3)⌋.
 fn skewed(e: isize) -> isize {
@@ -234,8 +363,13 @@ struct Scaler {
 
 // prescale returns the scaling constants for e, p.
 // lp must be log2Pow10(p).
-fn prescale(e: isize, p: isize, lp: isize) -> Scaler {
-    let (hi, lo) = pow10::TAB[(p - pow10::MIN) as usize];
+// If SMALL is set, the constants come from the small power-of-ten table.
+fn prescale<const SMALL: bool>(e: isize, p: isize, lp: isize) -> Scaler {
+    let (hi, lo) = if SMALL {
+        pow10::small(p)
+    } else {
+        pow10::TAB[(p - pow10::MIN) as usize]
+    };
     Scaler {
         pm: PmHiLo { hi, lo },
         s: -(e + lp + 3),
@@ -268,7 +402,7 @@ fn mul64(x: u64, y: u64) -> (u64, u64) {
 // It returns the number of bytes written to s.
 fn fmt(s: &mut [u8], d: u64, mut p: isize, nd: usize) -> usize {
     // Put digits into s, leaving room for decimal point.
-    format_base10(&mut s[1..=nd], d);
+    write_base10(&mut s[1..=nd], d);
     p += (nd - 1) as isize;
 
     // Move first digit up and insert decimal point.
@@ -299,7 +433,7 @@ fn fmt(s: &mut [u8], d: u64, mut p: isize, nd: usize) -> usize {
 }
 
 // Digits returns the number of decimal digits in d.
-fn digits(d: u64) -> usize {
+pub fn digits(d: u64) -> usize {
     let nd = log10_pow2(64 - d.leading_zeros() as isize);
     nd as usize + bool2::<usize>(d >= U64_POW10[nd as usize])
 }
@@ -318,10 +452,18 @@ const I2A: [u8; 200] = *b"\
     80818283848586878889\
     90919293949596979899";
 
-// formatBase10 formats the decimal representation of u into a.
+// format_base10 formats the decimal representation of u into a, which must
+// be at least digits(u) bytes long. If a is longer, leading zeros are filled
+// in as needed.
+pub fn format_base10(a: &mut [u8], u: u64) {
+    assert!(a.len() >= digits(u), "{} bytes cannot hold {u}", a.len());
+    write_base10(a, u);
+}
+
+// writeBase10 is formatBase10 without the length check.
 // The caller is responsible for ensuring that a is big enough to hold u.
 // If a is too big, leading zeros will be filled in as needed.
-fn format_base10(a: &mut [u8], mut u: u64) {
+fn write_base10(a: &mut [u8], mut u: u64) {
     let mut nd = a.len();
     while nd >= 8 {
         // Format last 8 digits (4 pairs).
@@ -367,9 +509,55 @@ fn format_base10(a: &mut [u8], mut u: u64) {
     }
 }
 
+// formatFinite formats the finite f into s and returns the number of bytes
+// written. The caller must ensure that s has room for at least MAX_LEN bytes.
+fn format_finite<const SMALL: bool>(s: &mut [u8], f: f64) -> usize {
+    format_with(s, f.is_sign_negative(), f == 0.0, || short::<SMALL>(f))
+}
+
+// formatFinite16 formats the finite 16-bit float with bits b
+// into s and returns the number of bytes written.
+// Mbits is as for unpack16.
+fn format_finite16<const SMALL: bool>(s: &mut [u8], b: u16, mbits: isize) -> usize {
+    format_with(s, b >> 15 != 0, b << 1 == 0, || {
+        let (m, e, min_exp) = unpack16(b, mbits);
+        short_unpacked::<SMALL>(m, e, 63 - mbits, min_exp)
+    })
+}
+
+// formatWith formats a finite value into s and returns the number of bytes
+// written. The value is negative if neg is set, and it is zero if zero is
+// set. Otherwise decimal computes the digits and exponent of its magnitude.
+fn format_with(
+    s: &mut [u8],
+    neg: bool,
+    zero: bool,
+    decimal: impl FnOnce() -> (u64, isize),
+) -> usize {
+    s[0] = b'-';
+    let begin = usize::from(neg);
+    let len = if zero {
+        s[begin..begin + 3].copy_from_slice(b"0.0");
+        3
+    } else {
+        let (d, p) = decimal();
+        fmt(&mut s[begin..], d, p, digits(d))
+    };
+    begin + len
+}
+
+// MAX_LEN is the maximum number of bytes written for a single finite f64:
+// sign, 17 digits, decimal point, and a 5-byte exponent.
+pub const MAX_LEN: usize = 24;
+
+// DIRECTED_MAX_LEN is the maximum number of bytes written by
+// Buffer::format_shortest_ceil and Buffer::format_shortest_floor, which may
+// need an 18th digit.
+pub const DIRECTED_MAX_LEN: usize = MAX_LEN + 1;
+
 #[derive(Default)]
 pub struct Buffer {
-    bytes: [u8; 24],
+    bytes: [u8; DIRECTED_MAX_LEN],
 }
 
 impl Buffer {
@@ -377,16 +565,339 @@ impl Buffer {
         Buffer::default()
     }
 
+    // format formats f, writing NaN as "NaN", +Inf as "inf",
+    // and -Inf as "-inf", the same as ryu and dtoa.
+    pub fn format(&mut self, f: f64) -> &str {
+        if f.is_finite() {
+            self.format_finite(f)
+        } else if f.is_nan() {
+            "NaN"
+        } else if f.is_sign_negative() {
+            "-inf"
+        } else {
+            "inf"
+        }
+    }
+
     pub fn format_finite(&mut self, f: f64) -> &str {
-        self.bytes[0] = b'-';
-        let begin = usize::from(f.is_sign_negative());
-        let len = if f == 0.0 {
-            self.bytes[begin..begin + 3].copy_from_slice(b"0.0");
-            3
+        let len = format_finite::<SMALL_TABLE>(&mut self.bytes, f);
+        unsafe { str::from_utf8_unchecked(&self.bytes[..len]) }
+    }
+
+    // format_finite_f16 formats the finite IEEE 754 binary16 value
+    // with the given bits.
+    pub fn format_finite_f16(&mut self, bits: u16) -> &str {
+        let len = format_finite16::<SMALL_TABLE>(&mut self.bytes, bits, 10);
+        unsafe { str::from_utf8_unchecked(&self.bytes[..len]) }
+    }
+
+    // format_finite_bf16 formats the finite bfloat16 value
+    // with the given bits.
+    pub fn format_finite_bf16(&mut self, bits: u16) -> &str {
+        let len = format_finite16::<SMALL_TABLE>(&mut self.bytes, bits, 7);
+        unsafe { str::from_utf8_unchecked(&self.bytes[..len]) }
+    }
+
+    // format_shortest_ceil formats the shortest decimal that is >= f and
+    // still round trips back to the finite f.
+    pub fn format_shortest_ceil(&mut self, f: f64) -> &str {
+        let up = f.is_sign_positive();
+        let len = format_with(&mut self.bytes, f.is_sign_negative(), f == 0.0, || {
+            directed::<SMALL_TABLE>(f, up)
+        });
+        unsafe { str::from_utf8_unchecked(&self.bytes[..len]) }
+    }
+
+    // format_shortest_floor formats the shortest decimal that is <= f and
+    // still round trips back to the finite f.
+    pub fn format_shortest_floor(&mut self, f: f64) -> &str {
+        let up = f.is_sign_negative();
+        let len = format_with(&mut self.bytes, f.is_sign_negative(), f == 0.0, || {
+            directed::<SMALL_TABLE>(f, up)
+        });
+        unsafe { str::from_utf8_unchecked(&self.bytes[..len]) }
+    }
+}
+
+// small::Buffer is like Buffer but always uses the small power-of-ten table,
+// whether or not the small-table feature is enabled.
+pub mod small {
+    use crate::{MAX_LEN, format_finite};
+
+    #[derive(Default)]
+    pub struct Buffer {
+        bytes: [u8; MAX_LEN],
+    }
+
+    impl Buffer {
+        pub fn new() -> Self {
+            Buffer::default()
+        }
+
+        // format formats f like crate::Buffer::format.
+        pub fn format(&mut self, f: f64) -> &str {
+            if f.is_finite() {
+                self.format_finite(f)
+            } else if f.is_nan() {
+                "NaN"
+            } else if f.is_sign_negative() {
+                "-inf"
+            } else {
+                "inf"
+            }
+        }
+
+        pub fn format_finite(&mut self, f: f64) -> &str {
+            let len = format_finite::<true>(&mut self.bytes, f);
+            unsafe { str::from_utf8_unchecked(&self.bytes[..len]) }
+        }
+    }
+}
+
+// format_finite_slice formats each of the finite values into out,
+// separated by sep, and returns the total number of bytes written.
+// The end offset of values[i] within out is stored into offsets[i];
+// its start is 0 for the first value and offsets[i-1]+1 afterward.
+//
+// Out must have room for MAX_LEN bytes at the position of each value,
+// so values.len() * (MAX_LEN + 1) bytes is always enough.
+// Offsets must be at least as long as values.
+pub fn format_finite_slice(
+    values: &[f64],
+    sep: u8,
+    out: &mut [u8],
+    offsets: &mut [usize],
+) -> usize {
+    assert!(offsets.len() >= values.len());
+    let mut n = 0;
+    for (i, &f) in values.iter().enumerate() {
+        if i > 0 {
+            out[n] = sep;
+            n += 1;
+        }
+        n += format_finite::<SMALL_TABLE>(&mut out[n..n + MAX_LEN], f);
+        offsets[i] = n;
+    }
+    n
+}
+
+// Fmt adapts f for use with write! and format!. Display produces the same
+// notation as Buffer::format_finite, and LowerExp produces the same notation
+// as core's {:e}. Both honor width, fill, alignment, sign, and zero padding.
+// A precision selects the number of digits after the decimal point instead of
+// the shortest round trip. Precisions above 17 need more digits than fixed
+// computes exactly, so core computes those. Non-finite values are formatted
+// by core.
+#[derive(Copy, Clone, Debug)]
+pub struct Fmt(pub f64);
+
+impl Display for Fmt {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if !self.0.is_finite() {
+            return Display::fmt(&self.0, formatter);
+        }
+        if self.0 == 0.0 && formatter.precision().is_none() {
+            return pad(formatter, self.0, b"0.0", b"");
+        }
+        self.fmt_exp(formatter, true)
+    }
+}
+
+impl LowerExp for Fmt {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if !self.0.is_finite() {
+            return LowerExp::fmt(&self.0, formatter);
+        }
+        self.fmt_exp(formatter, false)
+    }
+}
+
+impl Fmt {
+    // fmtExp formats the finite self in exponential notation. A long exponent
+    // is the 2- or 3-digit, always signed exponent written by fmt.
+    fn fmt_exp(self, formatter: &mut fmt::Formatter, long_exponent: bool) -> fmt::Result {
+        let f = self.0;
+        let (d, mut p, nd) = match formatter.precision() {
+            None if f == 0.0 => (0, 0, 1),
+            None => {
+                let (d, p) = short::<SMALL_TABLE>(f);
+                (d, p, digits(d))
+            }
+            Some(prec) if prec > 17 => return self.fmt_exact(formatter, prec, long_exponent),
+            Some(prec) => {
+                let nd = prec + 1;
+                let (d, p) = if f == 0.0 {
+                    (0, 1 - nd as isize)
+                } else {
+                    fixed::<SMALL_TABLE>(f, nd)
+                };
+                (d, p, nd)
+            }
+        };
+
+        // Digits with the decimal point after the first one.
+        let mut head = [0u8; 19];
+        write_base10(&mut head[1..=nd], d);
+        p += (nd - 1) as isize;
+        head[0] = head[1];
+        let mut len = 1;
+        if nd > 1 {
+            head[1] = b'.';
+            len = nd + 1;
+        }
+
+        let (tail, n) = exponent(p, long_exponent);
+        pad(formatter, f, &head[..len], &tail[..n])
+    }
+
+    // fmtExact is fmtExp for a precision above 17. Core formats |self| twice:
+    // once to find the length of the digits and the exponent, and once to
+    // write the digits, so that the padding can be computed in between.
+    fn fmt_exact(
+        self,
+        formatter: &mut fmt::Formatter,
+        prec: usize,
+        long_exponent: bool,
+    ) -> fmt::Result {
+        let abs = self.0.abs();
+        let mut measure = Digits::default();
+        write!(measure, "{abs:.prec$e}")?;
+        let p = if measure.negative {
+            -measure.exponent
         } else {
-            let (d, p) = short(f);
-            fmt(&mut self.bytes[begin..], d, p, digits(d))
+            measure.exponent
         };
-        unsafe { str::from_utf8_unchecked(&self.bytes[..begin + len]) }
+        let (tail, n) = exponent(p, long_exponent);
+        pad_with(formatter, self.0, measure.len, &tail[..n], |formatter| {
+            write!(Digits::to(formatter), "{abs:.prec$e}")
+        })
+    }
+}
+
+// Digits is a fmt::Write for core's {:e} output of a nonnegative value. By
+// default it measures the digits and parses the exponent, and if it has a
+// formatter, it writes the digits to it instead, leaving out the exponent.
+#[derive(Default)]
+struct Digits<'a, 'b> {
+    formatter: Option<&'a mut fmt::Formatter<'b>>,
+    len: usize,
+    in_exponent: bool,
+    negative: bool,
+    exponent: isize,
+}
+
+impl<'a, 'b> Digits<'a, 'b> {
+    fn to(formatter: &'a mut fmt::Formatter<'b>) -> Self {
+        Digits {
+            formatter: Some(formatter),
+            ..Digits::default()
+        }
+    }
+}
+
+impl fmt::Write for Digits<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let (digits, exponent) = if self.in_exponent {
+            ("", s)
+        } else if let Some((digits, exponent)) = s.split_once('e') {
+            self.in_exponent = true;
+            (digits, exponent)
+        } else {
+            (s, "")
+        };
+        self.len += digits.len();
+        for b in exponent.bytes() {
+            if b == b'-' {
+                self.negative = true;
+            } else {
+                self.exponent = self.exponent * 10 + isize::from(b - b'0');
+            }
+        }
+        match &mut self.formatter {
+            Some(formatter) => formatter.write_str(digits),
+            None => Ok(()),
+        }
+    }
+}
+
+// exponent returns the exponent p as written after the digits, 'e' included,
+// and its length. A long exponent is the 2- or 3-digit, always signed one.
+fn exponent(p: isize, long_exponent: bool) -> ([u8; 5], usize) {
+    let mut tail = [0u8; 5];
+    tail[0] = b'e';
+    let mut n = 1;
+    if p < 0 {
+        tail[n] = b'-';
+        n += 1;
+    } else if long_exponent {
+        tail[n] = b'+';
+        n += 1;
+    }
+    let a = p.unsigned_abs();
+    if a >= 100 {
+        tail[n] = b'0' + (a / 100) as u8;
+        n += 1;
+    }
+    if a >= 10 || long_exponent {
+        tail[n] = I2A[(a % 100) * 2];
+        n += 1;
+    }
+    tail[n] = I2A[(a % 100) * 2 + 1];
+    (tail, n + 1)
+}
+
+// pad writes the sign of f, then head, then tail, padded according to the
+// width, fill, alignment and zero padding flags.
+fn pad(formatter: &mut fmt::Formatter, f: f64, head: &[u8], tail: &[u8]) -> fmt::Result {
+    // head is ASCII.
+    let head = unsafe { str::from_utf8_unchecked(head) };
+    pad_with(formatter, f, head.len(), tail, |formatter| {
+        formatter.write_str(head)
+    })
+}
+
+// padWith is pad with head written by write_head, which writes len bytes.
+fn pad_with(
+    formatter: &mut fmt::Formatter,
+    f: f64,
+    len: usize,
+    tail: &[u8],
+    write_head: impl FnOnce(&mut fmt::Formatter) -> fmt::Result,
+) -> fmt::Result {
+    let sign = if f.is_sign_negative() {
+        "-"
+    } else if formatter.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let len = sign.len() + len + tail.len();
+    let padding = formatter.width().unwrap_or(0).saturating_sub(len);
+    let (pre, post) = if formatter.sign_aware_zero_pad() {
+        (0, 0)
+    } else {
+        match formatter.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        }
+    };
+    let fill = formatter.fill();
+
+    for _ in 0..pre {
+        formatter.write_char(fill)?;
+    }
+    formatter.write_str(sign)?;
+    if formatter.sign_aware_zero_pad() {
+        for _ in 0..padding {
+            formatter.write_char('0')?;
+        }
+    }
+    write_head(formatter)?;
+    // tail is ASCII.
+    formatter.write_str(unsafe { str::from_utf8_unchecked(tail) })?;
+    for _ in 0..post {
+        formatter.write_char(fill)?;
     }
+    Ok(())
 }
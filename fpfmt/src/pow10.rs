@@ -1,10 +1,11 @@
 pub const MIN: isize = -348;
 
-#[expect(dead_code)]
+#[cfg_attr(not(test), expect(dead_code))]
 pub const MAX: isize = 347;
 
 // pow10Tab holds 128-bit mantissas of powers of 10.
 // The values are scaled so the high bit is always set.
+// Each entry is ⌈10**p * 2**k⌉ in pmHiLo form, as recomputed by tests::pow10.
 pub static TAB: [(u64, u64); 696] = [
     (0xfa8fd5a0081c0289, 0xe8cd3796329f1bac), // 1e-348 * 2**1284
     (0x9c99e58405118196, 0xf18042bddfa3714b), // 1e-347 * 2**1280
@@ -703,3 +704,128 @@ pub static TAB: [(u64, u64); 696] = [
     (0xa7655d1d21039120, 0x9071ee70f0f1de6a), // 1e346 * 2**-1022
     (0xd13eb46469447568, 0xb48e6a0d2d2e5604), // 1e347 * 2**-1025
 ];
+
+// The small table keeps every STEP'th entry of TAB. The entries in between are
+// 10**p = 10**q * 5**r * 2**r for q = p - r, so they are computed by
+// multiplying the 128-bit mantissa for 10**q by the exact 5**r and rounding
+// up. That differs from TAB by at most 2, which SMALL_OFFSETS corrects for.
+// Everything is derived from TAB at compile time, so only the small tables
+// end up in a binary that does not use TAB directly.
+const STEP: usize = 27;
+
+static SMALL_TAB: [(u64, u64); TAB.len().div_ceil(STEP)] = {
+    let mut small_tab = [(0, 0); TAB.len().div_ceil(STEP)];
+    let mut i = 0;
+    while i < small_tab.len() {
+        small_tab[i] = TAB[i * STEP];
+        i += 1;
+    }
+    small_tab
+};
+
+// POW5[r] is 5**r.
+static POW5: [u64; STEP] = {
+    let mut pow5 = [1u64; STEP];
+    let mut r = 1;
+    while r < pow5.len() {
+        pow5[r] = 5 * pow5[r - 1];
+        r += 1;
+    }
+    pow5
+};
+
+// SMALL_OFFSETS packs 2 bits per entry of TAB: the amount by which
+// mul_pow5 overestimates it.
+static SMALL_OFFSETS: [u64; TAB.len().div_ceil(32)] = {
+    let mut offsets = [0u64; TAB.len().div_ceil(32)];
+    let mut i = 0;
+    while i < TAB.len() {
+        let (hi, lo) = TAB[i];
+        let exact = ((hi as u128) << 64).wrapping_sub(lo as u128);
+        let offset = mul_pow5(TAB[i - i % STEP], i % STEP) - exact;
+        assert!(offset < 4);
+        offsets[i / 32] |= (offset as u64) << (i % 32 * 2);
+        i += 1;
+    }
+    offsets
+};
+
+// mul_pow5 returns ⌈x * 5**r⌉, normalized so its high bit is set,
+// where x is the 128-bit mantissa that (hi, lo) represents.
+const fn mul_pow5((hi, lo): (u64, u64), r: usize) -> u128 {
+    let x = ((hi as u128) << 64).wrapping_sub(lo as u128);
+    if r == 0 {
+        return x;
+    }
+    // The 192-bit product is prod_hi<<64 | prod_lo.
+    let m = POW5[r] as u128;
+    let lo_prod = (x as u64 as u128) * m;
+    let prod_hi = (x >> 64) * m + (lo_prod >> 64);
+    let prod_lo = lo_prod as u64;
+    let s = prod_hi.leading_zeros();
+    let top = (prod_hi << s) | (prod_lo >> (64 - s)) as u128;
+    top + (prod_lo << s != 0) as u128
+}
+
+// small returns the entry of TAB for 10**p using only the small tables.
+pub fn small(p: isize) -> (u64, u64) {
+    let i = (p - MIN) as usize;
+    let offset = (SMALL_OFFSETS[i / 32] >> (i % 32 * 2)) & 3;
+    let x = mul_pow5(SMALL_TAB[i / STEP], i % STEP) - u128::from(offset);
+    let hi = (x >> 64) as u64 + u64::from(x as u64 != 0);
+    let lo = ((u128::from(hi) << 64) - x) as u64;
+    (hi, lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX, MIN, TAB, small};
+    use num_bigint::BigUint;
+    use std::vec::Vec;
+
+    // pow10 computes the entry of TAB for 10**p: the 128-bit value
+    // ⌈10**p * 2**k⌉ with its high bit set, stored as hi<<64 - lo.
+    fn pow10(p: isize) -> (u64, u64) {
+        let k = 127 - crate::log2_pow10(p);
+        let one = BigUint::from(1u32);
+        let ten = BigUint::from(10u32);
+        let num = ten.pow(p.max(0) as u32) << k.max(0);
+        let den = ten.pow((-p).max(0) as u32) << (-k).max(0);
+        let x = u128::try_from((num + &den - one) / den).unwrap();
+        assert_eq!(x >> 127, 1, "1e{p} * 2**{k} is not normalized");
+        let hi = (x >> 64) as u64 + u64::from(x as u64 != 0);
+        let lo = ((u128::from(hi) << 64) - x) as u64;
+        (hi, lo)
+    }
+
+    #[test]
+    fn small_table() {
+        for (i, &entry) in TAB.iter().enumerate() {
+            let p = MIN + i as isize;
+            assert!(entry == small(p), "small table is wrong for 1e{p}");
+        }
+    }
+
+    #[test]
+    fn table() {
+        assert_eq!(TAB.len() as isize, MAX - MIN + 1);
+        let comments: Vec<&str> = include_str!("pow10.rs")
+            .lines()
+            .filter_map(|line| {
+                let entry = line.trim_start().strip_prefix("(0x")?;
+                Some(entry.split_once("// ")?.1)
+            })
+            .collect();
+        assert_eq!(comments.len(), TAB.len());
+        for ((i, &entry), comment) in TAB.iter().enumerate().zip(comments) {
+            let p = MIN + i as isize;
+            let (hi, lo) = pow10(p);
+            assert!(
+                entry == (hi, lo),
+                "TAB entry for 1e{p} should be ({hi:#018x}, {lo:#018x})",
+            );
+            let k = 127 - crate::log2_pow10(p);
+            assert_eq!(comment, std::format!("1e{p} * 2**{k}"));
+        }
+    }
+}
@@ -0,0 +1,222 @@
+#![allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+
+// Checks fpfmt::binary128 against exact arithmetic, since there is no other
+// shortest formatter for binary128 to compare with.
+
+use fpfmt::binary128;
+use num_bigint::BigUint;
+use rand::rngs::{SmallRng, SysRng};
+use rand::{Rng as _, SeedableRng as _};
+use std::cmp::Ordering;
+
+const N: usize = if cfg!(miri) { 20 } else { 2_000 };
+
+const MAX_BITS: u128 = 0x7ffe_ffff_ffff_ffff_ffff_ffff_ffff_ffff;
+
+// exact returns m, e such that the absolute value of the binary128 value
+// with bits b is m * 2**e.
+fn exact(b: u128) -> (u128, i32) {
+    let biased = ((b >> 112) & 0x7fff) as i32;
+    let mantissa = b & ((1 << 112) - 1);
+    if biased == 0 {
+        (mantissa, -16494)
+    } else {
+        (mantissa | (1 << 112), biased - 16495)
+    }
+}
+
+// A Binary is the exact value n * 2**e.
+struct Binary {
+    n: BigUint,
+    e: i32,
+}
+
+impl Binary {
+    // at returns the value of bits b as a multiple of 2**e, which must be
+    // small enough for that to be an integer.
+    fn at(b: u128, e: i32) -> Binary {
+        let (m, exp) = exact(b);
+        Binary {
+            n: BigUint::from(m) << (exp - e),
+            e,
+        }
+    }
+
+    // cmp compares d * 10**p with self.
+    fn cmp(&self, d: u128, p: i32) -> Ordering {
+        let ten = BigUint::from(10u32);
+        let mut lhs = BigUint::from(d) << (-self.e).max(0);
+        let mut rhs = &self.n << self.e.max(0);
+        if p >= 0 {
+            lhs *= ten.pow(p.unsigned_abs());
+        } else {
+            rhs *= ten.pow(p.unsigned_abs());
+        }
+        lhs.cmp(&rhs)
+    }
+
+    // round returns self rounded to n significant digits, as d, p with
+    // d * 10**p ≈ self. It rounds to nearest, ties to even, if nearest is
+    // set, or else down.
+    fn round(&self, n: u32, nearest: bool) -> (u128, i32) {
+        let ten = BigUint::from(10u32);
+        let log2 = self.n.bits() as f64 + f64::from(self.e);
+        let mut p = (log2 * std::f64::consts::LOG10_2) as i32 - i32::try_from(n).unwrap();
+        loop {
+            let mut num = &self.n << self.e.max(0);
+            let mut den = BigUint::from(1u32) << (-self.e).max(0);
+            if p >= 0 {
+                den *= ten.pow(p.unsigned_abs());
+            } else {
+                num *= ten.pow(p.unsigned_abs());
+            }
+            let mut d = &num / &den;
+            if nearest {
+                let rem2 = (&num - &d * &den) << 1;
+                if rem2 > den || rem2 == den && d.bit(0) {
+                    d += 1u32;
+                }
+            }
+            if d >= ten.pow(n) {
+                p += 1;
+            } else if d < ten.pow(n - 1) {
+                p -= 1;
+            } else {
+                return (u128::try_from(d).unwrap(), p);
+            }
+        }
+    }
+}
+
+// decompose parses fpfmt's output into d, p such that the absolute value is
+// d * 10**p, with trailing zeros removed from d.
+fn decompose(repr: &str) -> (u128, i32) {
+    let repr = repr.trim_start_matches('-');
+    let (mantissa, exp) = repr.split_once('e').unwrap();
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let mut d: u128 = format!("{int}{frac}").parse().unwrap();
+    let mut p = exp.parse::<i32>().unwrap() - i32::try_from(frac.len()).unwrap();
+    while d != 0 && d.is_multiple_of(10) {
+        d /= 10;
+        p += 1;
+    }
+    (d, p)
+}
+
+fn check(bits: u128) {
+    let mut buffer = binary128::Buffer::new();
+    let repr = buffer.format_finite(bits);
+    assert_eq!(
+        repr.starts_with('-'),
+        bits >> 127 != 0,
+        "{bits:#034x}: {repr}"
+    );
+    let (d, p) = decompose(repr);
+    assert_eq!(binary128::to_decimal(bits), (d, p), "{bits:#034x}: {repr}");
+
+    // The rounding interval of |x|, inclusive if its mantissa is even. Its
+    // ends are multiples of a quarter of the spacing above |x|.
+    let abs = bits & !(1 << 127);
+    let e = exact(abs).1 - 2;
+    let value = Binary::at(abs, e);
+    let prev = Binary::at(abs - 1, e);
+    let next = if abs == MAX_BITS {
+        &value.n + (&value.n - &prev.n)
+    } else {
+        Binary::at(abs + 1, e).n
+    };
+    let lo = Binary {
+        n: (&prev.n + &value.n) >> 1,
+        e,
+    };
+    let hi = Binary {
+        n: (&value.n + &next) >> 1,
+        e,
+    };
+    let inclusive = abs.is_multiple_of(2);
+    let contains = |d: u128, p: i32| match (lo.cmp(d, p), hi.cmp(d, p)) {
+        (Ordering::Greater, Ordering::Less) => true,
+        (Ordering::Equal, _) | (_, Ordering::Equal) => inclusive,
+        _ => false,
+    };
+
+    assert!(contains(d, p), "{bits:#034x}: {repr} does not round trip");
+
+    // No decimal with fewer digits round trips: neither of the ones on
+    // either side of |x|.
+    let n = fpfmt_digits(d);
+    if n > 1 {
+        let (floor, p) = value.round(n - 1, false);
+        assert!(
+            !contains(floor, p) && !contains(floor + 1, p),
+            "{bits:#034x}: {repr} is not shortest",
+        );
+    }
+
+    // Of the decimals with n digits, the nearest one is chosen, except that
+    // the rounding interval of the smallest subnormal, with a single bit of
+    // precision, also contains a decimal with a larger exponent.
+    let (nearest, near_p) = value.round(n, true);
+    if contains(nearest, near_p) && abs != 1 {
+        assert_eq!(
+            (d, p),
+            decompose(&format!("{nearest}e{near_p}")),
+            "{bits:#034x}: {repr} is not nearest",
+        );
+    }
+}
+
+fn fpfmt_digits(d: u128) -> u32 {
+    d.checked_ilog10().unwrap() + 1
+}
+
+#[test]
+fn random() {
+    let mut rng = SmallRng::try_from_rng(&mut SysRng).unwrap();
+    for _ in 0..N {
+        let b = u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64());
+        if b & (0x7fff << 112) != 0x7fff << 112 && b << 1 != 0 {
+            check(b);
+        }
+    }
+}
+
+#[test]
+fn boundaries() {
+    // Every change in the number of significant bits of subnormals, then
+    // powers of two at a sample of the exponents, including the extremes.
+    let subnormals = (0..112).map(|k| 1 << k);
+    let step = if cfg!(miri) { 4096 } else { 19 };
+    let normals = (1..=0x7ffe).step_by(step).chain([2, 0x7ffe]);
+    for b in subnormals.chain(normals.map(|biased: u128| biased << 112)) {
+        for b in [b - 1, b, b + 1] {
+            if b != 0 {
+                check(b);
+            }
+        }
+    }
+    check(MAX_BITS);
+}
+
+#[test]
+fn simple() {
+    let mut buffer = binary128::Buffer::new();
+    // 1.0, 0.1, and the largest and smallest values.
+    assert_eq!(
+        buffer.format_finite(0x3fff_0000_0000_0000_0000_0000_0000_0000),
+        "1e+00"
+    );
+    assert_eq!(
+        buffer.format_finite(0x3ffb_9999_9999_9999_9999_9999_9999_999a),
+        "1e-01"
+    );
+    assert_eq!(
+        buffer.format_finite(MAX_BITS),
+        "1.189731495357231765085759326628007e+4932"
+    );
+    assert_eq!(buffer.format_finite(1), "6e-4966");
+    assert_eq!(buffer.format_finite(1 << 127), "-0.0");
+    assert_eq!(buffer.format(0x7fff << 112), "inf");
+    assert_eq!(buffer.format(0xffff << 112), "-inf");
+    assert_eq!(buffer.format(0x7fff_8000 << 96), "NaN");
+}
@@ -0,0 +1,122 @@
+#![allow(clippy::float_cmp, clippy::unreadable_literal)]
+
+// Deterministic cases aimed at the special paths in short: powers of two,
+// whose lower neighbor is closer than the upper one, and subnormals, whose
+// mantissa has fewer than 53 significant bits.
+
+mod common;
+
+// reference returns core's shortest digits and exponent for |f|, with
+// trailing zeros removed, the same as fpfmt::to_decimal.
+fn reference(f: f64) -> (u64, i32) {
+    common::decompose(&format!("{:e}", f.abs()))
+}
+
+fn check(f: f64) {
+    let (d, p) = fpfmt::to_decimal(f);
+    let (ref_d, ref_p) = reference(f);
+
+    assert!(
+        p == ref_p && (d == ref_d || common::tie(f, d, ref_d, p)),
+        "{f:e} ({:#018x}): fpfmt {d}e{p}, core {ref_d}e{ref_p}",
+        f.to_bits(),
+    );
+
+    let mut buffer = fpfmt::Buffer::new();
+    let repr = buffer.format_finite(f);
+    assert_eq!(
+        repr.parse::<f64>().unwrap().to_bits(),
+        f.to_bits(),
+        "{repr}"
+    );
+}
+
+fn check_neighborhood(f: f64) {
+    for f in [f.next_down(), f, f.next_up()] {
+        if f.is_finite() && f != 0.0 {
+            check(f);
+            check(-f);
+        }
+    }
+}
+
+#[test]
+fn powers_of_two() {
+    for e in -1074..=1023 {
+        let f = if e < -1022 {
+            f64::from_bits(1 << (e + 1074))
+        } else {
+            f64::from_bits(u64::try_from(e + 1023).unwrap() << 52)
+        };
+        check_neighborhood(f);
+    }
+}
+
+#[test]
+fn subnormals() {
+    // Every change in the number of significant bits of the mantissa.
+    for k in 1..=52 {
+        check_neighborhood(f64::from_bits(1 << k));
+        check_neighborhood(f64::from_bits((1 << k) - 1));
+    }
+    check_neighborhood(f64::MIN_POSITIVE);
+    check_neighborhood(f64::from_bits(1));
+    check_neighborhood(f64::from_bits((1 << 52) - 1));
+}
+
+#[test]
+fn powers_of_ten() {
+    for p in -323..=308 {
+        let f: f64 = format!("1e{p}").parse().unwrap();
+        check_neighborhood(f);
+    }
+}
+
+#[test]
+fn hard_cases() {
+    for f in [
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::EPSILON,
+        1e23,
+        9007199254740991.0,
+        9007199254740992.0,
+        // Ryu regression tests.
+        1.2345678,
+        2.989102097996e-312,
+        9.0608011534336e15,
+        4.708356024711512e18,
+        9.409340012568248e18,
+        1.8531501765868567e21,
+        -3.347727380279489e33,
+        1.9430376160308388e16,
+        -6.9741824662760956e19,
+        4.3816050601147837e18,
+        // Ryu looks-like-pow5 cases, where the mantissa is a multiple of a
+        // large power of five.
+        f64::from_bits(0x4830f0cf064dd592),
+        f64::from_bits(0x4840f0cf064dd592),
+        f64::from_bits(0x4850f0cf064dd592),
+        // Ryu min-and-max-shift cases.
+        1.7800590868057611e-307,
+        2.848094538889218e-306,
+        2.446494580089078e-296,
+        4.8929891601781557e-296,
+        1.8014398509481984e16,
+        3.6028797018963964e16,
+        2.900835519859558e-216,
+        5.801671039719115e-216,
+        3.196104012172126e-27,
+        // Extremes, and values whose neighbors straddle a power of ten.
+        5e-324,
+        1.7976931348623157e308,
+        2.2250738585072014e-308,
+        2.225073858507201e-308,
+        4.940656458412e-324,
+        9.5e-323,
+        1.0e-1,
+        1.0e22,
+    ] {
+        check_neighborhood(f);
+    }
+}
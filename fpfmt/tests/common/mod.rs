@@ -0,0 +1,57 @@
+#![allow(dead_code)]
+
+// Helpers shared by the integration tests.
+
+use num_bigint::BigUint;
+
+// decompose parses a decimal into d, p such that its absolute value is
+// d * 10**p, with trailing zeros removed from d.
+pub fn decompose(repr: &str) -> (u64, i32) {
+    let repr = repr.trim_start_matches('-');
+    let (mantissa, exp) = repr.split_once('e').unwrap();
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let mut d: u64 = format!("{int}{frac}").parse().unwrap();
+    let mut p = exp.parse::<i32>().unwrap() - i32::try_from(frac.len()).unwrap();
+    while d != 0 && d.is_multiple_of(10) {
+        d /= 10;
+        p += 1;
+    }
+    (d, p)
+}
+
+// exact returns num, den such that |f| = num / den.
+pub fn exact(f: f64) -> (BigUint, BigUint) {
+    let bits = f.to_bits();
+    let biased = (bits >> 52) & 0x7ff;
+    let mantissa = bits & ((1 << 52) - 1);
+    let (m, e) = if biased == 0 {
+        (mantissa, -1074)
+    } else {
+        (mantissa | (1 << 52), i32::try_from(biased).unwrap() - 1075)
+    };
+    let one = BigUint::from(1u32);
+    if e >= 0 {
+        (BigUint::from(m) << e, one)
+    } else {
+        (BigUint::from(m), one << -e)
+    }
+}
+
+// tie reports whether |f| is exactly halfway between d * 10**p and
+// other * 10**p, two neighboring decimals of which fpfmt must pick the even
+// d. Core and the other references may pick either one of a tie.
+pub fn tie(f: f64, d: u64, other: u64, p: i32) -> bool {
+    if d.abs_diff(other) != 1 || !d.is_multiple_of(2) {
+        return false;
+    }
+    // 2 * num / den == (d + other) * 10**p
+    let (num, den) = exact(f);
+    let ten = BigUint::from(10u32);
+    let (mut lhs, mut rhs) = (num << 1, BigUint::from(d + other) * den);
+    if p >= 0 {
+        rhs *= ten.pow(p.unsigned_abs());
+    } else {
+        lhs *= ten.pow(p.unsigned_abs());
+    }
+    lhs == rhs
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::float_cmp)]
+
+use num_bigint::BigUint;
+use rand::rngs::{SmallRng, SysRng};
+use rand::{Rng as _, SeedableRng as _};
+
+const N: usize = if cfg!(miri) { 50 } else { 20_000 };
+
+mod common;
+
+use common::{decompose, exact};
+
+// oracle returns the shortest d, p such that d * 10**p round trips back to
+// f and is >= |f| if up is set, or <= |f| otherwise, picking the one closest
+// to |f|. It tries every number of digits n, rounding |f| to n significant
+// digits in the requested direction using exact arithmetic.
+fn oracle(f: f64, up: bool) -> (u64, i32) {
+    let (num, den) = exact(f);
+    let ten = BigUint::from(10u32);
+    let exp = format!("{:e}", f.abs())
+        .split_once('e')
+        .unwrap()
+        .1
+        .parse::<i32>()
+        .unwrap();
+    for n in 1..=18 {
+        // Round |f| * 10**(n-1-x) to an integer, adjusting x until
+        // the result has exactly n digits.
+        let mut x = exp;
+        let d = loop {
+            let k = n - 1 - x;
+            let (mut num, mut den) = (num.clone(), den.clone());
+            if k >= 0 {
+                num *= ten.pow(k.unsigned_abs());
+            } else {
+                den *= ten.pow(k.unsigned_abs());
+            }
+            let mut d = &num / &den;
+            if up && d.clone() * &den != num {
+                d += 1u32;
+            }
+            if d >= ten.pow(n.unsigned_abs()) {
+                x += 1;
+            } else if d < ten.pow(n.unsigned_abs() - 1) {
+                x -= 1;
+            } else {
+                break u64::try_from(d).unwrap();
+            }
+        };
+        let p = x - (n - 1);
+        if format!("{d}e{p}").parse::<f64>().unwrap() == f.abs() {
+            return (d, p);
+        }
+    }
+    unreachable!()
+}
+
+fn check(f: f64) {
+    let mut buffer = fpfmt::Buffer::new();
+    let ceil = buffer.format_shortest_ceil(f);
+    assert_eq!(ceil.parse::<f64>().unwrap(), f, "{ceil}");
+    assert!(ceil.len() <= fpfmt::DIRECTED_MAX_LEN, "{ceil}");
+    assert_eq!(decompose(ceil), oracle(f, f > 0.0), "ceil {f:e}");
+
+    let floor = buffer.format_shortest_floor(f);
+    assert_eq!(floor.parse::<f64>().unwrap(), f, "{floor}");
+    assert!(floor.len() <= fpfmt::DIRECTED_MAX_LEN, "{floor}");
+    assert_eq!(decompose(floor), oracle(f, f < 0.0), "floor {f:e}");
+}
+
+#[test]
+fn random() {
+    let mut rng = SmallRng::try_from_rng(&mut SysRng).unwrap();
+    for _ in 0..N {
+        let f = f64::from_bits(rng.next_u64());
+        if f.is_finite() && f != 0.0 {
+            check(f);
+        }
+    }
+}
+
+#[test]
+fn powers_of_two() {
+    for e in -1074..=1023 {
+        let f = if e < -1022 {
+            f64::from_bits(1 << (e + 1074))
+        } else {
+            f64::from_bits(u64::try_from(e + 1023).unwrap() << 52)
+        };
+        for f in [f.next_down(), f, f.next_up()] {
+            if f.is_finite() && f != 0.0 {
+                check(f);
+            }
+        }
+    }
+}
+
+#[test]
+fn simple() {
+    let mut buffer = fpfmt::Buffer::new();
+    // 0.1 is slightly more than 1/10.
+    assert_eq!(buffer.format_shortest_ceil(0.1), "1.0000000000000001e-01");
+    assert_eq!(buffer.format_shortest_floor(0.1), "1e-01");
+    assert_eq!(buffer.format_shortest_ceil(-0.1), "-1e-01");
+    assert_eq!(
+        buffer.format_shortest_floor(-0.1),
+        "-1.0000000000000001e-01"
+    );
+    assert_eq!(buffer.format_shortest_ceil(1.0), "1e+00");
+    assert_eq!(buffer.format_shortest_floor(1.0), "1e+00");
+    assert_eq!(buffer.format_shortest_floor(0.0), "0.0");
+}
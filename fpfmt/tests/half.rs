@@ -0,0 +1,120 @@
+#![allow(clippy::float_cmp)]
+
+// Exhaustive tests of the 16-bit formats, which have few enough values to
+// check every one of them.
+
+mod common;
+
+use half::{bf16, f16};
+
+// check compares fpfmt's output for every finite nonzero value against the
+// shortest decimals that round trip, found by trying each number of digits.
+//
+// Whether a decimal round trips is decided by comparing it against the exact
+// midpoints between neighboring values, rather than using half's conversions
+// from f64, because bf16::from_f64 does not always round to nearest.
+fn check(format: fn(&mut fpfmt::Buffer, u16) -> &str, to_f64: fn(u16) -> f64) {
+    let mut buffer = fpfmt::Buffer::new();
+    for bits in 0..=u16::MAX {
+        let x = to_f64(bits);
+        if !x.is_finite() || x == 0.0 {
+            continue;
+        }
+        let abs = bits & 0x7fff;
+        let (a, prev) = (x.abs(), to_f64(abs - 1));
+        let next = match to_f64(abs + 1) {
+            next if next.is_finite() => next,
+            _ => a + (a - prev),
+        };
+        let (lo, hi) = (f64::midpoint(prev, a), f64::midpoint(a, next));
+        let roundtrips_f64 =
+            |v: f64| lo < v && v < hi || (v == lo || v == hi) && abs.is_multiple_of(2);
+        let roundtrips = |d: u64, p: i32| roundtrips_f64(format!("{d}e{p}").parse().unwrap());
+
+        let repr = format(&mut buffer, bits);
+        let value: f64 = repr.parse().unwrap();
+        assert!(
+            roundtrips_f64(value.abs()) && value.is_sign_negative() == x.is_sign_negative(),
+            "{x:e} ({bits:#06x}): {repr}",
+        );
+        let (d, p) = common::decompose(repr);
+
+        let (nearest, shortest) = (1..)
+            .find_map(|n| {
+                // |x| rounded to n digits, and its neighbors with n digits.
+                let repr = format!("{:.*e}", n - 1, x.abs());
+                let (mantissa, exp) = repr.split_once('e').unwrap();
+                let d: u64 = mantissa.replace('.', "").parse().unwrap();
+                let p = exp.parse::<i32>().unwrap() - i32::try_from(n - 1).unwrap();
+                if roundtrips(d, p) {
+                    let nearest = common::decompose(&repr);
+                    Some((Some(nearest), nearest))
+                } else {
+                    [d + 1, d - 1]
+                        .into_iter()
+                        .find(|&d| roundtrips(d, p))
+                        .map(|d| (None, common::decompose(&format!("{d}e{p}"))))
+                }
+            })
+            .unwrap();
+        assert_eq!(
+            fpfmt::digits(d),
+            fpfmt::digits(shortest.0),
+            "{x:e} ({bits:#06x}): fpfmt {repr}, shortest {}e{}",
+            shortest.0,
+            shortest.1,
+        );
+        // The rounding interval of a value with a single bit of precision,
+        // like the smallest bfloat16 subnormal 9.2e-41, can contain a
+        // shortest decimal with a larger exponent, 1e-40, than the nearest
+        // one, 9e-41. fpfmt prefers the larger exponent, which never happens
+        // with 53 bits.
+        let wide = abs == 1 && a < 1e-40;
+        if let Some((near_d, near_p)) = nearest
+            && !wide
+        {
+            assert!(
+                p == near_p && (d == near_d || common::tie(a, d, near_d, p)),
+                "{x:e} ({bits:#06x}): fpfmt {repr}, nearest {near_d}e{near_p}",
+            );
+        }
+    }
+}
+
+#[test]
+fn binary16() {
+    check(fpfmt::Buffer::format_finite_f16, |bits| {
+        f16::from_bits(bits).to_f64()
+    });
+}
+
+#[test]
+fn bfloat16() {
+    check(fpfmt::Buffer::format_finite_bf16, |bits| {
+        bf16::from_bits(bits).to_f64()
+    });
+}
+
+#[test]
+fn simple() {
+    let mut buffer = fpfmt::Buffer::new();
+    assert_eq!(
+        buffer.format_finite_f16(f16::from_f32(0.1).to_bits()),
+        "1e-01"
+    );
+    assert_eq!(buffer.format_finite_f16(f16::MAX.to_bits()), "6.55e+04");
+    assert_eq!(
+        buffer.format_finite_f16(f16::MIN_POSITIVE_SUBNORMAL.to_bits()),
+        "6e-08"
+    );
+    assert_eq!(buffer.format_finite_f16(f16::NEG_ZERO.to_bits()), "-0.0");
+    assert_eq!(
+        buffer.format_finite_bf16(bf16::from_f32(0.1).to_bits()),
+        "1e-01"
+    );
+    assert_eq!(buffer.format_finite_bf16(bf16::MAX.to_bits()), "3.39e+38");
+    assert_eq!(
+        buffer.format_finite_bf16(bf16::from_f32(-1.0).to_bits()),
+        "-1e+00"
+    );
+}
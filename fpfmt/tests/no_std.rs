@@ -0,0 +1,35 @@
+// Runs the roundtrip check from a #![no_std] crate. Run it with
+// `cargo test --no-default-features` to exercise fpfmt without std.
+
+#![no_std]
+#![allow(clippy::float_cmp, clippy::unreadable_literal)]
+
+const N: usize = if cfg!(miri) { 500 } else { 1_000_000 };
+
+#[test]
+fn roundtrip() {
+    let mut fpfmt_buffer = fpfmt::Buffer::new();
+    let mut state = 0x9e3779b97f4a7c15u64;
+    let mut fail = 0;
+
+    for _ in 0..N {
+        // xorshift64*
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        let bits = state.wrapping_mul(0x2545f4914f6cdd1d);
+        let float = f64::from_bits(bits);
+        if !float.is_finite() {
+            continue;
+        }
+        let fpfmt = fpfmt_buffer.format_finite(float);
+        let matches = fpfmt
+            .parse::<f64>()
+            .is_ok_and(|roundtrip| roundtrip == float);
+        if !matches {
+            fail += 1;
+        }
+    }
+
+    assert!(fail == 0, "{fail} mismatches");
+}
@@ -35,3 +35,157 @@ fn roundtrip() {
 
     assert!(fail == 0, "{fail} mismatches");
 }
+
+#[test]
+fn format_finite_slice() {
+    let values = [0.0, -1.5, 1e-300, f64::MAX, 0.1, -f64::MIN_POSITIVE];
+    let mut out = [0u8; 6 * (fpfmt::MAX_LEN + 1)];
+    let mut offsets = [0usize; 6];
+    let len = fpfmt::format_finite_slice(&values, b',', &mut out, &mut offsets);
+
+    let mut fpfmt_buffer = fpfmt::Buffer::new();
+    let mut expected = String::new();
+    for (i, &value) in values.iter().enumerate() {
+        if i > 0 {
+            expected.push(',');
+        }
+        expected.push_str(fpfmt_buffer.format_finite(value));
+        assert_eq!(offsets[i], expected.len());
+    }
+    assert_eq!(&out[..len], expected.as_bytes());
+}
+
+#[test]
+fn to_decimal() {
+    let mut rng = SmallRng::try_from_rng(&mut SysRng).unwrap();
+
+    for _ in 0..N / 100 {
+        let bits = rng.next_u64();
+        let float = f64::from_bits(bits);
+        if !float.is_finite() {
+            continue;
+        }
+        let (d, p) = fpfmt::to_decimal(float);
+        assert!(
+            d % 10 != 0 || d == 0,
+            "{float:?} has trailing zeros: {d}e{p}"
+        );
+        assert_eq!(format!("{d}e{p}").parse::<f64>().unwrap(), float.abs());
+
+        let nd = fpfmt::digits(d);
+        let mut buffer = [0u8; 20];
+        fpfmt::format_base10(&mut buffer[..nd], d);
+        assert_eq!(str::from_utf8(&buffer[..nd]).unwrap(), d.to_string());
+    }
+
+    assert_eq!(fpfmt::to_decimal(0.0), (0, 0));
+    assert_eq!(fpfmt::to_decimal(-0.0), (0, 0));
+    assert_eq!(fpfmt::to_decimal(1.0), (1, 0));
+    assert_eq!(fpfmt::to_decimal(-2.5e-300), (25, -301));
+
+    let mut buffer = [b'x'; 5];
+    fpfmt::format_base10(&mut buffer, 42);
+    assert_eq!(buffer, *b"00042");
+    fpfmt::format_base10(&mut [], 0);
+}
+
+#[test]
+#[should_panic = "2 bytes cannot hold 123"]
+fn format_base10_too_short() {
+    fpfmt::format_base10(&mut [0; 2], 123);
+}
+
+#[test]
+fn fmt_adapter() {
+    let mut fpfmt_buffer = fpfmt::Buffer::new();
+    let mut rng = SmallRng::try_from_rng(&mut SysRng).unwrap();
+
+    for _ in 0..N / 100 {
+        let float = f64::from_bits(rng.next_u64());
+        if !float.is_finite() {
+            continue;
+        }
+        let fmt = fpfmt::Fmt(float);
+        let display = fmt.to_string();
+        assert_eq!(display, fpfmt_buffer.format_finite(float));
+        let lower_exp = format!("{fmt:e}");
+        assert_eq!(lower_exp.parse::<f64>().unwrap(), float);
+        let (display_mantissa, _) = display.split_once('e').unwrap();
+        let (lower_exp_mantissa, _) = lower_exp.split_once('e').unwrap();
+        assert_eq!(lower_exp_mantissa, display_mantissa);
+        let prec = (rng.next_u32() % 41) as usize;
+        assert_eq!(format!("{fmt:.prec$e}"), format!("{float:.prec$e}"));
+    }
+
+    for float in [0.1, -1.0 / 3.0, 2f64.powi(-30), f64::MAX, 5e-324, 0.0, -0.0] {
+        let fmt = fpfmt::Fmt(float);
+        for prec in 0..=40 {
+            assert_eq!(format!("{fmt:.prec$e}"), format!("{float:.prec$e}"));
+            assert_eq!(format!("{fmt:>60.prec$e}"), format!("{float:>60.prec$e}"));
+            assert_eq!(format!("{fmt:+060.prec$e}"), format!("{float:+060.prec$e}"));
+        }
+    }
+
+    let fmt = fpfmt::Fmt(1.5);
+    assert_eq!(format!("{fmt}"), "1.5e+00");
+    assert_eq!(format!("{fmt:+}"), "+1.5e+00");
+    assert_eq!(format!("{fmt:>10}"), "   1.5e+00");
+    assert_eq!(format!("{fmt:*<10}"), "1.5e+00***");
+    assert_eq!(format!("{fmt:^11}"), "  1.5e+00  ");
+    assert_eq!(format!("{fmt:010}"), "0001.5e+00");
+    assert_eq!(format!("{:010}", fpfmt::Fmt(-1.5)), "-001.5e+00");
+    assert_eq!(format!("{fmt:.3}"), "1.500e+00");
+    assert_eq!(format!("{fmt:.0}"), "2e+00");
+    assert_eq!(format!("{fmt:+.2e}"), "+1.50e0");
+    assert_eq!(format!("{fmt:.20e}"), "1.50000000000000000000e0");
+    assert_eq!(
+        format!("{:.20}", fpfmt::Fmt(0.1)),
+        "1.00000000000000005551e-01",
+    );
+    assert_eq!(
+        format!("{:^32.20}", fpfmt::Fmt(-2.5e-300)),
+        "  -2.49999999999999997976e-300  ",
+    );
+    assert_eq!(format!("{}", fpfmt::Fmt(0.0)), "0.0");
+    assert_eq!(format!("{}", fpfmt::Fmt(-0.0)), "-0.0");
+    assert_eq!(format!("{:e}", fpfmt::Fmt(-0.0)), "-0e0");
+    assert_eq!(format!("{:.2}", fpfmt::Fmt(0.0)), "0.00e+00");
+    assert_eq!(format!("{:.2e}", fpfmt::Fmt(0.0)), "0.00e0");
+    assert_eq!(format!("{:e}", fpfmt::Fmt(1e-300)), "1e-300");
+    assert_eq!(format!("{:>5}", fpfmt::Fmt(f64::NAN)), "  NaN");
+    assert_eq!(format!("{:e}", fpfmt::Fmt(f64::NEG_INFINITY)), "-inf");
+}
+
+#[test]
+fn format_nonfinite() {
+    let mut fpfmt_buffer = fpfmt::Buffer::new();
+    assert_eq!(fpfmt_buffer.format(f64::NAN), "NaN");
+    assert_eq!(fpfmt_buffer.format(-f64::NAN), "NaN");
+    assert_eq!(fpfmt_buffer.format(f64::INFINITY), "inf");
+    assert_eq!(fpfmt_buffer.format(f64::NEG_INFINITY), "-inf");
+    assert_eq!(fpfmt_buffer.format(-1.5), "-1.5e+00");
+
+    let mut small_buffer = fpfmt::small::Buffer::new();
+    assert_eq!(small_buffer.format(f64::NAN), "NaN");
+    assert_eq!(small_buffer.format(f64::INFINITY), "inf");
+    assert_eq!(small_buffer.format(f64::NEG_INFINITY), "-inf");
+    assert_eq!(small_buffer.format(-1.5), "-1.5e+00");
+}
+
+#[test]
+fn small_table() {
+    let mut fpfmt_buffer = fpfmt::Buffer::new();
+    let mut small_buffer = fpfmt::small::Buffer::new();
+    let mut rng = SmallRng::try_from_rng(&mut SysRng).unwrap();
+
+    for _ in 0..N / 10 {
+        let float = f64::from_bits(rng.next_u64());
+        if !float.is_finite() {
+            continue;
+        }
+        assert_eq!(
+            small_buffer.format_finite(float),
+            fpfmt_buffer.format_finite(float),
+        );
+    }
+}
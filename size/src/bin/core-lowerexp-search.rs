@@ -0,0 +1,31 @@
+use arrayvec::ArrayString;
+use half::f16;
+use std::fmt::Write as _;
+use std::hint;
+
+// The precision search of core[LowerExp,search] in src/main.rs, for one f16.
+fn main() {
+    let value = hint::black_box(f16::ONE);
+    let x = value.to_f64();
+    let roundtrips = |repr: &str| f16::from_f32(repr.parse().unwrap()).to_bits() == value.to_bits();
+    let mut buffer = ArrayString::<24>::new();
+    for prec in 0.. {
+        buffer.clear();
+        write!(buffer, "{x:.prec$e}").unwrap();
+        if roundtrips(&buffer) {
+            break;
+        }
+        let rounded: f64 = buffer.parse().unwrap();
+        let exp: i32 = buffer.split_once('e').unwrap().1.parse().unwrap();
+        let step = 10f64.powi(exp - i32::try_from(prec).unwrap());
+        for neighbor in [rounded - step, rounded + step] {
+            buffer.clear();
+            write!(buffer, "{neighbor:.prec$e}").unwrap();
+            if roundtrips(&buffer) {
+                hint::black_box(buffer.as_str());
+                return;
+            }
+        }
+    }
+    hint::black_box(buffer.as_str());
+}
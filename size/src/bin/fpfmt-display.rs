@@ -0,0 +1,10 @@
+use arrayvec::ArrayString;
+use std::fmt::Write as _;
+use std::hint;
+
+fn main() {
+    let value = hint::black_box(1.0f64);
+    let mut buffer = ArrayString::<24>::new();
+    write!(buffer, "{}", fpfmt::Fmt(value)).unwrap();
+    hint::black_box(buffer.as_str());
+}
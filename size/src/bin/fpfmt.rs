@@ -0,0 +1,6 @@
+use std::hint;
+
+fn main() {
+    let value = hint::black_box(1.0f64);
+    hint::black_box(fpfmt::Buffer::new().format_finite(value));
+}
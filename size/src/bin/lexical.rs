@@ -0,0 +1,8 @@
+use lexical_core::FormattedSize;
+use std::hint;
+
+fn main() {
+    let value = hint::black_box(1.0f64);
+    let mut buffer = [0u8; f64::FORMATTED_SIZE_DECIMAL];
+    hint::black_box(lexical_core::write(value, &mut buffer));
+}
@@ -0,0 +1,6 @@
+use std::hint;
+
+fn main() {
+    let _value = hint::black_box(1.0f64);
+    hint::black_box("");
+}
@@ -1,77 +1,566 @@
+use crate::external::External;
+use crate::warmup::{self, Warmup};
 use anyhow::{Result, bail};
+use half::{bf16, f16};
 use std::env;
+use std::ffi::OsString;
+use std::process;
+use std::time::Duration;
 
 pub struct Args {
-    pub benchmark: Vec<(&'static str, Type)>,
+    pub benchmark: Vec<(&'static crate::Impl, Type)>,
     pub unpredictable: bool,
+    pub batch: bool,
+    pub nonfinite: bool,
+    pub size: bool,
+    pub json: bool,
+    // Precision groups to generate and measure, in increasing order.
+    pub precision_f32: Vec<usize>,
+    pub precision_f64: Vec<usize>,
+    pub precision_f16: Vec<usize>,
+    pub precision_bf16: Vec<usize>,
+    #[cfg(feature = "f128")]
+    pub precision_f128: Vec<usize>,
+    // Number of threads measuring concurrently, if --threads was given.
+    pub threads: Option<usize>,
+    // Number of values formatted between cache evictions, if --cold was
+    // given.
+    pub cold: Option<usize>,
+    pub latency: bool,
+    // Run before measuring each library and type.
+    pub warmup: Option<Warmup>,
+    // Busy loop before measuring the first library.
+    pub spin_up: Option<Duration>,
+    pub verbose: bool,
+    // Report each library's time net of the null implementation's.
+    pub subtract_null: bool,
+    // Compare each library's statically dispatched time to its time through
+    // F<T>.
+    pub static_dispatch: bool,
 }
 
 pub enum Type {
     F32(crate::F<f32>),
     F64(crate::F<f64>),
+    F16(crate::F<f16>),
+    BF16(crate::F<bf16>),
+    #[cfg(feature = "f128")]
+    F128(crate::F<f128>),
+    ExternalF32(External),
+    ExternalF64(External),
 }
 
+impl Type {
+    fn name(&self) -> &'static str {
+        match self {
+            Type::F32(_) | Type::ExternalF32(_) => "f32",
+            Type::F64(_) | Type::ExternalF64(_) => "f64",
+            Type::F16(_) => "f16",
+            Type::BF16(_) => "bf16",
+            #[cfg(feature = "f128")]
+            Type::F128(_) => "f128",
+        }
+    }
+
+    fn max_precision(&self) -> usize {
+        match self {
+            Type::F32(_) | Type::ExternalF32(_) => 9,
+            Type::F64(_) | Type::ExternalF64(_) => 17,
+            Type::F16(_) => 5,
+            Type::BF16(_) => 4,
+            #[cfg(feature = "f128")]
+            Type::F128(_) => 36,
+        }
+    }
+}
+
+const USAGE: &str = "\
+Usage: dtoa-benchmark [OPTIONS] [LIB[:f32|:f64|:f16|:bf16|:f128]]...
+
+Benchmarks every library if none is named. Use --list to see the names.
+f128 requires building with --features f128 on a nightly compiler.
+
+Options:
+      --unpredictable       Interleave a mixed-precision baseline into each group
+      --batch               Compare per-value and batch formatting of f64
+      --include-nonfinite   Mix NaN and infinity into the inputs
+      --size                Report each library's code and read-only data size
+      --json                Measure serializing the inputs with serde_json
+      --precision [TYPE:]LIST
+                            Measure only these precisions, like 15..17 or 1,3,5
+      --threads N           Measure N threads formatting concurrently
+      --cold[=BATCH]        Evict the caches before every BATCH values (default 1)
+      --latency             Report the distribution of single-value latency
+      --warmup PASSES|DURATION
+                            Format values untimed before measuring each library
+      --spin-up DURATION    Busy loop before measuring, like 2s or 500ms
+  -v, --verbose             Report the discarded warmup timings
+      --static              Compare static dispatch with the fn pointer harness
+      --subtract-null       Subtract the harness overhead measured using null
+      --external=NAME[:f32|:f64]=COMMAND
+                            Benchmark a helper process, see src/external.rs;
+                            COMMAND is split like a shell would, with quotes
+      --list                List the libraries and the types they support
+  -h, --help                Print this message
+";
+
 pub fn parse() -> Result<Args> {
     let mut args = env::args_os();
     args.next().unwrap();
 
     let mut benchmark = Vec::new();
+    // The library and type of each LIB:TYPE argument.
+    let mut typed = Vec::new();
     let mut unpredictable = false;
-    'args: for arg in args {
+    let mut batch = false;
+    let mut nonfinite = false;
+    let mut size = false;
+    let mut json = false;
+    let mut precision_f32 = None;
+    let mut precision_f64 = None;
+    let mut precision_f16 = None;
+    let mut precision_bf16 = None;
+    #[cfg(feature = "f128")]
+    let mut precision_f128 = None;
+    let mut threads = None;
+    let mut cold = None;
+    let mut latency = false;
+    let mut warmup = None;
+    let mut spin_up = None;
+    let mut verbose = false;
+    let mut subtract_null = false;
+    let mut static_dispatch = false;
+    'args: while let Some(arg) = args.next() {
         if let Some(arg) = arg.to_str() {
             if arg == "--unpredictable" {
                 unpredictable = true;
                 continue;
             }
+            if arg == "--batch" {
+                batch = true;
+                continue;
+            }
+            if arg == "--include-nonfinite" {
+                nonfinite = true;
+                continue;
+            }
+            if arg == "--size" {
+                size = true;
+                continue;
+            }
+            if arg == "--json" {
+                json = true;
+                continue;
+            }
+            if arg == "--help" || arg == "-h" {
+                print!("{USAGE}");
+                process::exit(0);
+            }
+            if arg == "--list" {
+                list();
+                process::exit(0);
+            }
+            if arg == "--precision" || arg.starts_with("--precision=") {
+                let spec = match arg.strip_prefix("--precision=") {
+                    Some(spec) => spec.to_owned(),
+                    None => match args.next().map(OsString::into_string) {
+                        Some(Ok(spec)) => spec,
+                        _ => bail!("expected --precision LIST"),
+                    },
+                };
+                let (ty, list) = match spec.split_once(':') {
+                    Some((ty, list)) => (Some(ty), list),
+                    None => (None, spec.as_str()),
+                };
+                match ty {
+                    None => {
+                        let list = parse_precision(list, 17)?;
+                        let upto = |max| list.iter().copied().filter(move |&prec| prec <= max);
+                        precision_f32.get_or_insert_with(Vec::new).extend(upto(9));
+                        precision_f16.get_or_insert_with(Vec::new).extend(upto(5));
+                        precision_bf16.get_or_insert_with(Vec::new).extend(upto(4));
+                        #[cfg(feature = "f128")]
+                        precision_f128.get_or_insert_with(Vec::new).extend(&list);
+                        precision_f64.get_or_insert_with(Vec::new).extend(list);
+                    }
+                    Some("f32") => {
+                        let list = parse_precision(list, 9)?;
+                        precision_f32.get_or_insert_with(Vec::new).extend(list);
+                    }
+                    Some("f64") => {
+                        let list = parse_precision(list, 17)?;
+                        precision_f64.get_or_insert_with(Vec::new).extend(list);
+                    }
+                    Some("f16") => {
+                        let list = parse_precision(list, 5)?;
+                        precision_f16.get_or_insert_with(Vec::new).extend(list);
+                    }
+                    Some("bf16") => {
+                        let list = parse_precision(list, 4)?;
+                        precision_bf16.get_or_insert_with(Vec::new).extend(list);
+                    }
+                    #[cfg(feature = "f128")]
+                    Some("f128") => {
+                        let list = parse_precision(list, 36)?;
+                        precision_f128.get_or_insert_with(Vec::new).extend(list);
+                    }
+                    Some(_) => bail!("unsupported: --precision {spec}"),
+                }
+                continue;
+            }
+            if arg == "--threads" || arg.starts_with("--threads=") {
+                let n = match arg.strip_prefix("--threads=") {
+                    Some(n) => Some(n.to_owned()),
+                    None => args.next().and_then(|n| n.into_string().ok()),
+                };
+                match n.and_then(|n| n.parse::<usize>().ok()) {
+                    Some(n) if n > 0 => threads = Some(n),
+                    _ => bail!("expected --threads N"),
+                }
+                continue;
+            }
+            if arg == "--warmup" || arg.starts_with("--warmup=") {
+                let spec = match arg.strip_prefix("--warmup=") {
+                    Some(spec) => spec.to_owned(),
+                    None => match args.next().map(OsString::into_string) {
+                        Some(Ok(spec)) => spec,
+                        _ => bail!("expected --warmup PASSES|DURATION"),
+                    },
+                };
+                warmup = Some(Warmup::parse(&spec)?);
+                continue;
+            }
+            if arg == "--spin-up" || arg.starts_with("--spin-up=") {
+                let spec = match arg.strip_prefix("--spin-up=") {
+                    Some(spec) => spec.to_owned(),
+                    None => match args.next().map(OsString::into_string) {
+                        Some(Ok(spec)) => spec,
+                        _ => bail!("expected --spin-up DURATION"),
+                    },
+                };
+                spin_up = Some(warmup::parse_duration(&spec)?);
+                continue;
+            }
+            if arg == "--verbose" || arg == "-v" {
+                verbose = true;
+                continue;
+            }
+            if arg == "--static" {
+                static_dispatch = true;
+                continue;
+            }
+            if arg == "--subtract-null" {
+                subtract_null = true;
+                continue;
+            }
+            if arg == "--latency" {
+                latency = true;
+                continue;
+            }
+            if arg == "--cold" {
+                cold = Some(1);
+                continue;
+            }
+            if let Some(batch) = arg.strip_prefix("--cold=") {
+                match batch.parse::<usize>() {
+                    Ok(batch) if batch > 0 => cold = Some(batch),
+                    _ => bail!("expected --cold=BATCH"),
+                }
+                continue;
+            }
+            if let Some(external) = arg.strip_prefix("--external=") {
+                parse_external(external, &mut benchmark, &mut typed)?;
+                continue;
+            }
             let (lib, ty) = match arg.split_once(':') {
                 Some((lib, ty)) => (lib, Some(ty)),
                 None => (arg, None),
             };
             for imp in crate::IMPLS {
                 if imp.name == lib {
-                    match ty {
-                        None => {
-                            if let Some(f) = imp.f32 {
-                                benchmark.push((imp.name, Type::F32(f)));
-                            }
-                            if let Some(f) = imp.f64 {
-                                benchmark.push((imp.name, Type::F64(f)));
-                            }
-                            continue 'args;
-                        }
-                        Some("f32") => {
-                            if let Some(f) = imp.f32 {
-                                benchmark.push((imp.name, Type::F32(f)));
-                                continue 'args;
-                            }
+                    if push_impl(&mut benchmark, imp, ty) {
+                        if ty.is_some() {
+                            typed.push((imp.name, benchmark.last().unwrap().1.name()));
                         }
-                        Some("f64") => {
-                            if let Some(f) = imp.f64 {
-                                benchmark.push((imp.name, Type::F64(f)));
-                                continue 'args;
-                            }
-                        }
-                        Some(_) => {}
+                        continue 'args;
+                    }
+                    if ty.is_none() {
+                        continue 'args;
                     }
+                    bail!("unsupported: {arg} ({lib} supports {})", types(imp));
                 }
             }
+            if !arg.starts_with('-')
+                && let Some(imp) = crate::IMPLS
+                    .iter()
+                    .min_by_key(|imp| edit_distance(imp.name, lib))
+                && edit_distance(imp.name, lib) <= 3
+            {
+                bail!("unsupported: {arg} (did you mean {}?)", imp.name);
+            }
         }
         bail!("unsupported: {}", arg.display());
     }
 
     if benchmark.is_empty() {
         for imp in crate::IMPLS {
-            if let Some(f) = imp.f32 {
-                benchmark.push((imp.name, Type::F32(f)));
-            }
-            if let Some(f) = imp.f64 {
-                benchmark.push((imp.name, Type::F64(f)));
-            }
+            push_impl(&mut benchmark, imp, None);
         }
     }
 
+    let external = benchmark
+        .iter()
+        .any(|(_imp, ty)| matches!(ty, Type::ExternalF32(_) | Type::ExternalF64(_)));
+
+    // Each of these replaces the default measurement, so at most one can be
+    // used at a time, and external implementations only support the default.
+    let modes = [
+        ("--batch", batch),
+        ("--json", json),
+        ("--threads", threads.is_some()),
+        ("--cold", cold.is_some()),
+        ("--latency", latency),
+        ("--static", static_dispatch),
+    ];
+    let mut enabled = modes.iter().filter(|(_flag, enabled)| *enabled);
+    if let Some((flag, _)) = enabled.next() {
+        if let Some((other, _)) = enabled.next() {
+            bail!("{flag} does not support {other}");
+        }
+        if external {
+            bail!("{flag} does not support --external");
+        }
+    }
+    if subtract_null {
+        if let Some((flag, _)) = modes.iter().find(|(_flag, enabled)| *enabled) {
+            bail!("--subtract-null does not support {flag}");
+        }
+        if external {
+            bail!("--subtract-null does not support --external");
+        }
+    }
+    // The protocol only has finite values.
+    if external && nonfinite {
+        bail!("--include-nonfinite does not support --external");
+    }
+    if static_dispatch && nonfinite {
+        bail!("--static does not support --include-nonfinite");
+    }
+    if latency && unpredictable {
+        bail!("--latency does not support --unpredictable");
+    }
+    // Only the first values of each group are measured cold, and with
+    // --unpredictable about half of those are from the mixed baseline, so
+    // subtracting the baseline would not leave the group's own time.
+    if cold.is_some() && unpredictable {
+        bail!("--cold does not support --unpredictable");
+    }
+
+    if nonfinite {
+        if batch {
+            bail!("--batch does not support --include-nonfinite");
+        }
+        // Measure each library's entry point that accepts NaN and infinity.
+        benchmark = benchmark
+            .into_iter()
+            .filter_map(|(imp, ty)| match ty {
+                Type::F32(_) => Some((imp, Type::F32(imp.format_f32()?))),
+                Type::F64(_) => Some((imp, Type::F64(imp.format_f64()?))),
+                // The 16-bit formats and f128 are only measured using finite
+                // inputs.
+                Type::F16(_) | Type::BF16(_) => None,
+                #[cfg(feature = "f128")]
+                Type::F128(_) => None,
+                // The protocol does not distinguish format from format_finite.
+                Type::ExternalF32(_) | Type::ExternalF64(_) => Some((imp, ty)),
+            })
+            .collect();
+    }
+
+    // An untyped --precision leaves out the precisions a type does not have,
+    // which can be all of the ones it names. That is an error for the types
+    // named on the command line, and the others are skipped.
+    let selects_nothing = |ty: &Type| {
+        let list = match ty {
+            Type::F32(_) | Type::ExternalF32(_) => &precision_f32,
+            Type::F64(_) | Type::ExternalF64(_) => &precision_f64,
+            Type::F16(_) => &precision_f16,
+            Type::BF16(_) => &precision_bf16,
+            #[cfg(feature = "f128")]
+            Type::F128(_) => &precision_f128,
+        };
+        list.as_ref().is_some_and(Vec::is_empty)
+    };
+    if let Some((imp, ty)) = benchmark
+        .iter()
+        .find(|(imp, ty)| selects_nothing(ty) && typed.contains(&(imp.name, ty.name())))
+    {
+        bail!(
+            "--precision selects nothing for {}:{} (expected 1..{})",
+            imp.name,
+            ty.name(),
+            ty.max_precision(),
+        );
+    }
+    let len = benchmark.len();
+    benchmark.retain(|(_imp, ty)| !selects_nothing(ty));
+    if benchmark.is_empty() && len > 0 {
+        bail!("--precision selects nothing for the named libraries");
+    }
+
+    // Types with no precision selected are measured at every precision.
+    let precision = |list: Option<Vec<usize>>, max| {
+        let mut list = list.unwrap_or_else(|| (1..=max).collect());
+        list.sort_unstable();
+        list.dedup();
+        list
+    };
+
     Ok(Args {
         benchmark,
         unpredictable,
+        batch,
+        nonfinite,
+        size,
+        json,
+        precision_f32: precision(precision_f32, 9),
+        precision_f64: precision(precision_f64, 17),
+        precision_f16: precision(precision_f16, 5),
+        precision_bf16: precision(precision_bf16, 4),
+        #[cfg(feature = "f128")]
+        precision_f128: precision(precision_f128, 36),
+        threads,
+        cold,
+        latency,
+        warmup,
+        spin_up,
+        verbose,
+        subtract_null,
+        static_dispatch,
     })
 }
+
+// --external=NAME[:TYPE]=COMMAND
+fn parse_external(
+    arg: &str,
+    benchmark: &mut Vec<(&'static crate::Impl, Type)>,
+    typed: &mut Vec<(&'static str, &'static str)>,
+) -> Result<()> {
+    let Some((lib, command)) = arg.split_once('=') else {
+        bail!("expected --external=NAME=COMMAND");
+    };
+    let (lib, ty) = match lib.split_once(':') {
+        Some((lib, ty)) => (lib, Some(ty)),
+        None => (lib, None),
+    };
+    let external = External::new(command)?;
+    let imp = External::leak_impl(lib);
+    match ty {
+        None => {
+            benchmark.push((imp, Type::ExternalF32(external.clone())));
+            benchmark.push((imp, Type::ExternalF64(external)));
+        }
+        Some("f32") => benchmark.push((imp, Type::ExternalF32(external))),
+        Some("f64") => benchmark.push((imp, Type::ExternalF64(external))),
+        Some(_) => bail!("unsupported: --external={arg}"),
+    }
+    if ty.is_some() {
+        typed.push((imp.name, benchmark.last().unwrap().1.name()));
+    }
+    Ok(())
+}
+
+// Parses a comma-separated list of precisions and inclusive ranges, like
+// "1,3,15..17".
+fn parse_precision(list: &str, max: usize) -> Result<Vec<usize>> {
+    let mut precisions = Vec::new();
+    for item in list.split(',') {
+        let (lo, hi) = match item.split_once("..") {
+            Some((lo, hi)) => (lo, hi.strip_prefix('=').unwrap_or(hi)),
+            None => (item, item),
+        };
+        let (Ok(lo), Ok(hi)) = (lo.parse::<usize>(), hi.parse::<usize>()) else {
+            bail!("unsupported precision: {item}");
+        };
+        if lo == 0 || lo > hi || hi > max {
+            bail!("unsupported precision: {item} (expected 1..{max})");
+        }
+        precisions.extend(lo..=hi);
+    }
+    Ok(precisions)
+}
+
+// Adds imp's function for ty, or for every type that imp supports if ty is
+// None. Returns whether anything was added.
+fn push_impl(
+    benchmark: &mut Vec<(&'static crate::Impl, Type)>,
+    imp: &'static crate::Impl,
+    ty: Option<&str>,
+) -> bool {
+    let len = benchmark.len();
+    let entries = [
+        ("f32", imp.f32.map(Type::F32)),
+        ("f64", imp.f64.map(Type::F64)),
+        ("f16", imp.f16.map(Type::F16)),
+        ("bf16", imp.bf16.map(Type::BF16)),
+    ];
+    #[cfg(feature = "f128")]
+    let entries = entries
+        .into_iter()
+        .chain([("f128", imp.f128.map(Type::F128))]);
+    for (name, entry) in entries {
+        if ty.is_none_or(|ty| ty == name)
+            && let Some(entry) = entry
+        {
+            benchmark.push((imp, entry));
+        }
+    }
+    benchmark.len() > len
+}
+
+fn types(imp: &crate::Impl) -> String {
+    let types = [
+        ("f32", imp.f32.is_some()),
+        ("f64", imp.f64.is_some()),
+        ("f16", imp.f16.is_some()),
+        ("bf16", imp.bf16.is_some()),
+    ];
+    #[cfg(feature = "f128")]
+    let types = types.into_iter().chain([("f128", imp.f128.is_some())]);
+    let types: Vec<&str> = types
+        .into_iter()
+        .filter_map(|(name, supported)| supported.then_some(name))
+        .collect();
+    if types.is_empty() {
+        "nothing".to_owned()
+    } else {
+        types.join(", ")
+    }
+}
+
+fn list() {
+    let width = crate::IMPLS
+        .iter()
+        .map(|imp| imp.name.len())
+        .max()
+        .unwrap_or(0);
+    for imp in crate::IMPLS {
+        println!("{:width$}  {}", imp.name, types(imp));
+    }
+}
+
+// Levenshtein distance between a and b, counting chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
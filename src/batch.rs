@@ -0,0 +1,43 @@
+use crate::data::DataForType;
+use crate::{Batch, F, PASSES};
+use std::hint;
+use std::time::Duration;
+
+pub fn measure(data: &DataForType<f64, 17>, f: F<f64>, batch: Option<Batch>) {
+    println!("  f64 batch");
+    let values = &data.mixed;
+    let mut out = Vec::with_capacity(values.len() * (fpfmt::MAX_LEN + 1));
+    let mut offsets = vec![0; values.len()];
+
+    // Baseline: one call per value, appending each repr to a shared buffer.
+    let duration = crate::fastest_trial(|| {
+        out.clear();
+        for (i, &value) in values.iter().enumerate() {
+            f(value, &mut |repr| {
+                if i > 0 {
+                    out.push(b',');
+                }
+                out.extend_from_slice(repr.as_bytes());
+            });
+            offsets[i] = out.len();
+        }
+        hint::black_box((&out, &offsets));
+    });
+    report("per-value", duration, values.len());
+
+    if let Some(batch) = batch {
+        out.resize(values.len() * (fpfmt::MAX_LEN + 1), 0);
+        let duration = crate::fastest_trial(|| {
+            let len = batch(values, &mut out, &mut offsets);
+            hint::black_box((&out[..len], &offsets));
+        });
+        report("batch", duration, values.len());
+    }
+}
+
+fn report(label: &str, duration: Duration, count: usize) {
+    println!(
+        "    ({label}, {:.2})",
+        duration.as_secs_f64() * 1e9 / (PASSES * count) as f64,
+    );
+}
@@ -0,0 +1,78 @@
+use crate::data::DataForType;
+use crate::{F, TRIALS, traits};
+use std::hint;
+use std::time::{Duration, Instant};
+
+// Number of values of each group that are measured. Evicting the caches
+// dominates the running time, so only a prefix of each group is used.
+const COUNT: usize = if cfg!(miri) { 10 } else { 256 };
+
+// Several times larger than a core's L1 and L2, so that walking over it
+// evicts the libraries' tables from both and from most of a shared L3.
+const SCRATCH_LEN: usize = if cfg!(miri) { 1 << 10 } else { 8 << 20 };
+const CACHE_LINE: usize = 64;
+
+// Evicts the caches before every `batch` values, timing only the formatting,
+// and prints the time per value of each precision group.
+pub fn measure<T, const N: usize>(data: &DataForType<T, N>, f: F<T>, batch: usize)
+where
+    T: traits::Float,
+{
+    let Some((_prec, first)) = data.groups().next() else {
+        return;
+    };
+    println!("  {} (cold, batch {batch})", T::NAME);
+    let mut scratch = vec![0u8; SCRATCH_LEN];
+
+    // Cost of reading the clock around an empty batch, to be subtracted from
+    // every group.
+    let overhead = measure_cold(&mut scratch, first, batch, |_chunk| {});
+
+    let measure = |scratch: &mut [u8], vec: &[T]| {
+        let duration = measure_cold(scratch, vec, batch, |chunk| {
+            for &value in chunk {
+                f(value, &mut |repr| {
+                    hint::black_box(repr);
+                });
+            }
+        });
+        duration.saturating_sub(overhead)
+    };
+
+    for (prec, vec) in data.groups() {
+        let duration = measure(&mut scratch, vec);
+        println!(
+            "    ({prec}, {:.2})",
+            duration.as_secs_f64() * 1e9 / COUNT.min(vec.len()) as f64,
+        );
+    }
+}
+
+// Returns the fastest trial's total time spent in `format`, over batches of
+// the first COUNT values of vec.
+fn measure_cold<T>(
+    scratch: &mut [u8],
+    vec: &[T],
+    batch: usize,
+    mut format: impl FnMut(&[T]),
+) -> Duration {
+    let mut fastest = Duration::MAX;
+    for _trial in 0..TRIALS {
+        let mut duration = Duration::ZERO;
+        for chunk in vec[..COUNT.min(vec.len())].chunks(batch) {
+            evict(scratch);
+            let begin = Instant::now();
+            format(hint::black_box(chunk));
+            duration += begin.elapsed();
+        }
+        fastest = Ord::min(fastest, duration);
+    }
+    fastest
+}
+
+fn evict(scratch: &mut [u8]) {
+    for byte in scratch.iter_mut().step_by(CACHE_LINE) {
+        *byte = byte.wrapping_add(1);
+    }
+    hint::black_box(scratch);
+}
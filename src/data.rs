@@ -1,4 +1,6 @@
+use crate::args::Args;
 use crate::traits;
+use half::{bf16, f16};
 use rand::SeedableRng as _;
 use rand::distr::{Distribution, StandardUniform};
 use rand::rngs::SmallRng;
@@ -7,6 +9,10 @@ use rand::seq::SliceRandom as _;
 pub struct Data {
     pub f32: DataForType<f32, 9>,
     pub f64: DataForType<f64, 17>,
+    pub f16: DataForType<f16, 5>,
+    pub bf16: DataForType<bf16, 4>,
+    #[cfg(feature = "f128")]
+    pub f128: DataForType<f128, 36>,
 }
 
 pub struct DataForType<T, const N: usize> {
@@ -17,11 +23,14 @@ pub struct DataForType<T, const N: usize> {
 }
 
 impl Data {
-    pub fn random(count: usize, unpredictable: bool) -> Self {
-        let mut rng = SmallRng::seed_from_u64(1);
+    pub fn random(count: usize, args: &Args) -> Self {
         Data {
-            f32: DataForType::random(&mut rng, count, unpredictable),
-            f64: DataForType::random(&mut rng, count, unpredictable),
+            f32: DataForType::random(count, args, &args.precision_f32),
+            f64: DataForType::random(count, args, &args.precision_f64),
+            f16: DataForType::random(count, args, &args.precision_f16),
+            bf16: DataForType::random(count, args, &args.precision_bf16),
+            #[cfg(feature = "f128")]
+            f128: DataForType::random(count, args, &args.precision_f128),
         }
     }
 }
@@ -31,31 +40,49 @@ where
     T: traits::Float,
     StandardUniform: Distribution<T::Bits>,
 {
-    fn random(rng: &mut SmallRng, count: usize, unpredictable: bool) -> Self {
+    // Generates the groups for the given 1-based precisions and leaves the
+    // other groups of by_precision empty. Each group, and the mixed one, has
+    // its own seed, so its values do not depend on which others are selected.
+    fn random(count: usize, args: &Args, precision: &[usize]) -> Self {
+        let unpredictable = args.unpredictable;
         let mut mixed = Vec::new();
         let mut by_precision = [const { Vec::new() }; N];
-        if unpredictable {
+        let mut mixed_rng = SmallRng::seed_from_u64(seed(T::NAME, 0));
+        if unpredictable || args.batch {
             mixed.reserve_exact(count);
             for i in 0..count {
-                mixed.push(sample(rng, i % N));
+                mixed.push(sample(&mut mixed_rng, i % N));
             }
-            mixed.shuffle(rng);
-            for (prec, vec) in by_precision.iter_mut().enumerate() {
+            mixed.shuffle(&mut mixed_rng);
+        }
+        for &prec in precision {
+            let mut rng = SmallRng::seed_from_u64(seed(T::NAME, prec));
+            let vec = &mut by_precision[prec - 1];
+            if unpredictable {
                 vec.reserve_exact(count * 2);
                 vec.extend_from_slice(&mixed);
-                for _ in 0..count {
-                    vec.push(sample(rng, prec));
-                }
-                vec.shuffle(rng);
-            }
-        } else {
-            for (prec, vec) in by_precision.iter_mut().enumerate() {
+            } else {
                 vec.reserve_exact(count);
-                for _i in 0..count {
-                    vec.push(sample(rng, prec));
+            }
+            for _i in 0..count {
+                vec.push(sample(&mut rng, prec - 1));
+            }
+            if unpredictable {
+                vec.shuffle(&mut rng);
+            }
+            if args.nonfinite {
+                insert_nonfinite(vec);
+                if unpredictable {
+                    vec.shuffle(&mut rng);
                 }
             }
         }
+        if args.nonfinite {
+            insert_nonfinite(&mut mixed);
+            if unpredictable {
+                mixed.shuffle(&mut mixed_rng);
+            }
+        }
         DataForType {
             count,
             mixed,
@@ -65,6 +92,38 @@ where
     }
 }
 
+impl<T, const N: usize> DataForType<T, N> {
+    // The groups selected by --precision, with their 1-based precision.
+    pub fn groups(&self) -> impl Iterator<Item = (usize, &[T])> {
+        let groups = self.by_precision.iter().enumerate();
+        groups.filter_map(|(i, vec)| (!vec.is_empty()).then_some((i + 1, vec.as_slice())))
+    }
+}
+
+// The seed of the values of the given type and 1-based precision, or of the
+// mixed values for precision 0: FNV-1a of the name and precision.
+fn seed(name: &str, prec: usize) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for byte in name.bytes().chain(prec.to_le_bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+// Replaces one in every NONFINITE_EVERY values with NaN, +inf or -inf, in
+// equal proportion.
+fn insert_nonfinite<T>(vec: &mut [T])
+where
+    T: traits::Float,
+{
+    const NONFINITE_EVERY: usize = 10;
+    let nonfinite = [T::NAN, T::INFINITY, T::NEG_INFINITY];
+    for (i, value) in vec.iter_mut().step_by(NONFINITE_EVERY).enumerate() {
+        *value = nonfinite[i % nonfinite.len()];
+    }
+}
+
 fn sample<T>(rng: &mut SmallRng, prec: usize) -> T
 where
     T: traits::Float,
@@ -75,7 +134,7 @@ where
         let float = T::from_bits(bits);
         if float.is_finite() {
             // Convert to string with limited digits, and convert it back.
-            return format!("{float:.prec$e}").parse().unwrap();
+            return float.round_to_precision(prec);
         }
     }
 }
@@ -0,0 +1,226 @@
+// The f32 and f64 functions in IMPLS that can also be measured statically,
+// defined once as Format impls. IMPLS holds dynamic::<T, I> as the library's
+// F<T> and measure_once::<T, I> as its Static<T>, for measuring how much of
+// each library's time is spent on the fn pointer call and the dyn FnMut
+// callback of F<T>.
+
+use crate::data::DataForType;
+use crate::{F, Static, traits};
+use arrayvec::ArrayString;
+use lexical_core::FormattedSize;
+use std::fmt::Write as _;
+use std::hint;
+use std::time::Duration;
+
+pub trait Format<T> {
+    fn format(value: T, f: impl FnOnce(&str));
+}
+
+// Formats value using I, as the F<T> of the library in IMPLS.
+pub fn dynamic<T, I>(value: T, f: &mut dyn FnMut(&str))
+where
+    I: Format<T>,
+{
+    I::format(value, f);
+}
+
+pub fn measure_once<T, I>(data: &[T]) -> Duration
+where
+    T: traits::Float,
+    I: Format<T>,
+{
+    crate::fastest_trial(|| {
+        for &value in data {
+            I::format(value, |repr| {
+                hint::black_box(repr);
+            });
+        }
+    })
+}
+
+// Prints the statically dispatched time per value of each precision group,
+// followed by the dynamically dispatched time and the difference.
+pub fn measure<T, const N: usize>(data: &DataForType<T, N>, f: F<T>, measure_static: Static<T>)
+where
+    T: traits::Float,
+{
+    if data.groups().next().is_none() {
+        return;
+    }
+    println!("  {} (static dispatch)", T::NAME);
+    let mut dynamic = Vec::new();
+    crate::time_groups(
+        data,
+        |vec| crate::measure_once(vec, f),
+        |_prec, ns| dynamic.push(ns),
+    );
+    let mut dynamic = dynamic.into_iter();
+    crate::time_groups(data, measure_static, |prec, ns| {
+        let dynamic = dynamic.next().unwrap();
+        println!(
+            "    ({prec}, {ns:.2}) dyn {dynamic:.2} ({:+.2})",
+            dynamic - ns,
+        );
+    });
+}
+
+pub struct CoreDisplay;
+
+impl Format<f32> for CoreDisplay {
+    fn format(value: f32, f: impl FnOnce(&str)) {
+        let mut buffer = ArrayString::<327>::new();
+        write!(buffer, "{value}").unwrap();
+        f(&buffer);
+    }
+}
+
+impl Format<f64> for CoreDisplay {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        let mut buffer = ArrayString::<327>::new();
+        write!(buffer, "{value}").unwrap();
+        f(&buffer);
+    }
+}
+
+pub struct CoreLowerExp;
+
+impl Format<f32> for CoreLowerExp {
+    fn format(value: f32, f: impl FnOnce(&str)) {
+        let mut buffer = ArrayString::<24>::new();
+        write!(buffer, "{value:e}").unwrap();
+        f(&buffer);
+    }
+}
+
+impl Format<f64> for CoreLowerExp {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        let mut buffer = ArrayString::<24>::new();
+        write!(buffer, "{value:e}").unwrap();
+        f(&buffer);
+    }
+}
+
+pub struct Dtoa;
+
+impl Format<f32> for Dtoa {
+    fn format(value: f32, f: impl FnOnce(&str)) {
+        f(dtoa::Buffer::new().format_finite(value));
+    }
+}
+
+impl Format<f64> for Dtoa {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        f(dtoa::Buffer::new().format_finite(value));
+    }
+}
+
+pub struct Lexical;
+
+impl Format<f32> for Lexical {
+    fn format(value: f32, f: impl FnOnce(&str)) {
+        let mut buffer = [0u8; f32::FORMATTED_SIZE_DECIMAL];
+        let bytes = lexical_core::write(value, &mut buffer);
+        f(unsafe { str::from_utf8_unchecked(bytes) });
+    }
+}
+
+impl Format<f64> for Lexical {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        let mut buffer = [0u8; f64::FORMATTED_SIZE_DECIMAL];
+        let bytes = lexical_core::write(value, &mut buffer);
+        f(unsafe { str::from_utf8_unchecked(bytes) });
+    }
+}
+
+pub struct Ryu;
+
+impl Format<f32> for Ryu {
+    fn format(value: f32, f: impl FnOnce(&str)) {
+        f(ryu::Buffer::new().format_finite(value));
+    }
+}
+
+impl Format<f64> for Ryu {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        f(ryu::Buffer::new().format_finite(value));
+    }
+}
+
+pub struct Dragonbox;
+
+impl Format<f64> for Dragonbox {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        f(dragonbox::Buffer::new().format_finite(value));
+    }
+}
+
+#[cfg(not(miri))]
+pub struct Teju;
+
+#[cfg(not(miri))]
+impl Format<f32> for Teju {
+    fn format(value: f32, f: impl FnOnce(&str)) {
+        f(teju::Buffer::new().format_finite(value));
+    }
+}
+
+#[cfg(not(miri))]
+impl Format<f64> for Teju {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        f(teju::Buffer::new().format_finite(value));
+    }
+}
+
+pub struct Zmij;
+
+impl Format<f32> for Zmij {
+    fn format(value: f32, f: impl FnOnce(&str)) {
+        f(zmij::Buffer::new().format_finite(value));
+    }
+}
+
+impl Format<f64> for Zmij {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        f(zmij::Buffer::new().format_finite(value));
+    }
+}
+
+pub struct Fpfmt;
+
+impl Format<f64> for Fpfmt {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        f(fpfmt::Buffer::new().format_finite(value));
+    }
+}
+
+pub struct FpfmtSmall;
+
+impl Format<f64> for FpfmtSmall {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        f(fpfmt::small::Buffer::new().format_finite(value));
+    }
+}
+
+pub struct FpfmtDisplay;
+
+impl Format<f64> for FpfmtDisplay {
+    fn format(value: f64, f: impl FnOnce(&str)) {
+        let mut buffer = ArrayString::<24>::new();
+        write!(buffer, "{}", fpfmt::Fmt(value)).unwrap();
+        f(&buffer);
+    }
+}
+
+pub struct Null;
+
+impl Format<f32> for Null {
+    fn format(_value: f32, f: impl FnOnce(&str)) {
+        f("");
+    }
+}
+
+impl Format<f64> for Null {
+    fn format(_value: f64, f: impl FnOnce(&str)) {
+        f("");
+    }
+}
@@ -0,0 +1,75 @@
+use std::fs;
+use std::process::Command;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Describes the machine and build the results were measured with, printed
+// at the top of the output so that saved results are self-describing.
+pub fn print() {
+    println!("# cpu: {}", cpu().as_deref().unwrap_or("unknown"));
+    println!(
+        "# cores: {}",
+        thread::available_parallelism().map_or(0, usize::from),
+    );
+    println!("# rustc: {}", env!("VERSION_RUSTC"));
+    println!("# target: {}", env!("BUILD_TARGET"));
+    println!("# rustflags: {}", env!("BUILD_RUSTFLAGS"));
+    println!("# target features: {}", env!("BUILD_TARGET_FEATURES"));
+    println!("# kernel: {}", kernel().as_deref().unwrap_or("unknown"));
+    println!("# date: {}", timestamp());
+}
+
+fn cpu() -> Option<String> {
+    if let Ok(cpuinfo) = fs::read_to_string("/proc/cpuinfo") {
+        for line in cpuinfo.lines() {
+            if let Some((key, value)) = line.split_once(':')
+                && key.trim() == "model name"
+            {
+                return Some(value.trim().to_owned());
+            }
+        }
+    }
+    // macOS
+    command("sysctl", &["-n", "machdep.cpu.brand_string"])
+}
+
+fn kernel() -> Option<String> {
+    command("uname", &["-sr"])
+}
+
+fn command(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let stdout = stdout.trim();
+    (output.status.success() && !stdout.is_empty()).then(|| stdout.to_owned())
+}
+
+// Current time in UTC, like 2025-01-31T12:34:56Z.
+fn timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let (days, secs) = (secs / 86400, secs % 86400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+    )
+}
+
+// Howard Hinnant's algorithm for converting days since 1970-01-01 into a
+// proleptic Gregorian date.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,174 @@
+// Benchmarks an implementation that lives in a separate process, for
+// libraries not written in Rust. The harness talks to the helper over its
+// stdin and stdout using a line-based text protocol:
+//
+//     harness: f64 4 12              type, trials, passes
+//     harness: 1 100000              label, number of values
+//     harness: 3ff0000000000000      one line of hex bits per value
+//     ...
+//     helper:  1 8310042             label, nanoseconds
+//     harness: 2 100000
+//     ...
+//
+// For each group the helper parses the values up front, then formats all of
+// them once per pass, and replies with the total duration of its fastest
+// trial. The label is "mixed" for the baseline group of --unpredictable, or
+// else the precision. The harness closes stdin after the last group. Only
+// finite values are sent, so --include-nonfinite is not supported.
+//
+// The command is split into the program and its arguments at whitespace,
+// except inside single or double quotes or after a backslash, so paths with
+// spaces can be passed as --external='name="/path/with space/helper" arg'.
+//
+// See examples/external.rs for a helper written in Rust.
+
+use crate::data::DataForType;
+use crate::{Impl, Notation, PASSES, TRIALS, traits};
+use anyhow::{Context as _, Result, bail};
+use std::io::{BufRead as _, BufReader, BufWriter, Write as _};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct External {
+    command: Vec<String>,
+}
+
+impl External {
+    pub fn new(command: &str) -> Result<Self> {
+        let command = split_command(command)?;
+        if command.is_empty() {
+            bail!("--external requires a command");
+        }
+        Ok(External { command })
+    }
+
+    // An Impl with no functions, for naming the external implementation in
+    // the output.
+    pub fn leak_impl(name: &str) -> &'static Impl {
+        Box::leak(Box::new(Impl {
+            name: Box::leak(name.to_owned().into_boxed_str()),
+            version: None,
+            url: None,
+            notation: Notation::Shortest,
+            color: None,
+            f32: None,
+            f64: None,
+            f32_format: None,
+            f64_format: None,
+            f16: None,
+            bf16: None,
+            #[cfg(feature = "f128")]
+            f128: None,
+            f32_static: None,
+            f64_static: None,
+            batch: None,
+        }))
+    }
+
+    pub fn measure<T, const N: usize>(&self, data: &DataForType<T, N>) -> Result<()>
+    where
+        T: traits::Float,
+    {
+        if data.groups().next().is_none() {
+            return Ok(());
+        }
+        let mut groups = Vec::new();
+        if data.unpredictable {
+            groups.push(("mixed".to_owned(), data.mixed.as_slice()));
+        }
+        for (prec, vec) in data.groups() {
+            groups.push((prec.to_string(), vec));
+        }
+
+        let mut child = Command::new(&self.command[0])
+            .args(&self.command[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("failed to spawn {}", self.command[0]))?;
+        let mut stdin = BufWriter::new(child.stdin.take().unwrap());
+        let mut stdout = BufReader::new(child.stdout.take().unwrap());
+
+        writeln!(stdin, "{} {TRIALS} {PASSES}", T::NAME)?;
+        let mut timings = Vec::new();
+        let mut line = String::new();
+        for (label, vec) in groups {
+            writeln!(stdin, "{label} {}", vec.len())?;
+            for value in vec {
+                writeln!(stdin, "{:x}", value.to_bits())?;
+            }
+            stdin.flush()?;
+
+            line.clear();
+            stdout.read_line(&mut line)?;
+            let nanos = match line.trim_end().split_once(' ') {
+                Some((reply, nanos)) if reply == label => nanos.parse::<u64>().ok(),
+                _ => None,
+            };
+            let Some(nanos) = nanos else {
+                bail!("{}: unexpected reply {line:?}", self.command[0]);
+            };
+            timings.push(Duration::from_nanos(nanos));
+        }
+        drop(stdin);
+
+        let status = child.wait()?;
+        if !status.success() {
+            bail!("{}: {status}", self.command[0]);
+        }
+
+        let mut timings = timings.into_iter();
+        crate::measure_with(data, |_vec| timings.next().unwrap());
+        Ok(())
+    }
+}
+
+// Splits command into words at unquoted whitespace. Single quotes keep
+// everything up to the closing quote, double quotes keep everything but
+// allow \" and \\, and a backslash elsewhere keeps the next character.
+fn split_command(command: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = None::<String>;
+    let mut chars = command.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            ch if ch.is_whitespace() => words.extend(word.take()),
+            '\'' => {
+                let word = word.get_or_insert_default();
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(ch) => word.push(ch),
+                        None => bail!("unterminated ' in --external command: {command}"),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_default();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(ch @ ('"' | '\\')) => word.push(ch),
+                            Some(ch) => {
+                                word.push('\\');
+                                word.push(ch);
+                            }
+                            None => bail!("unterminated \" in --external command: {command}"),
+                        },
+                        Some(ch) => word.push(ch),
+                        None => bail!("unterminated \" in --external command: {command}"),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some(ch) => word.get_or_insert_default().push(ch),
+                None => bail!("trailing \\ in --external command: {command}"),
+            },
+            ch => word.get_or_insert_default().push(ch),
+        }
+    }
+    words.extend(word);
+    Ok(words)
+}
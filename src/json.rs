@@ -0,0 +1,60 @@
+use crate::data::DataForType;
+use crate::{F, traits};
+use serde::Serialize;
+use serde_json::ser::Formatter;
+use std::hint;
+use std::io::{self, Write};
+
+// A serde_json formatter that writes floats using one Impl instead of ryu.
+pub struct ImplFormatter<T> {
+    f: F<T>,
+}
+
+impl<T> ImplFormatter<T> {
+    fn write_float<W>(&mut self, writer: &mut W, value: T) -> io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        let mut result = Ok(());
+        (self.f)(value, &mut |repr| {
+            result = writer.write_all(repr.as_bytes());
+        });
+        result
+    }
+}
+
+impl Formatter for ImplFormatter<f32> {
+    fn write_f32<W>(&mut self, writer: &mut W, value: f32) -> io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        self.write_float(writer, value)
+    }
+}
+
+impl Formatter for ImplFormatter<f64> {
+    fn write_f64<W>(&mut self, writer: &mut W, value: f64) -> io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        self.write_float(writer, value)
+    }
+}
+
+// Measures serializing each precision group as a JSON array.
+pub fn measure<T, const N: usize>(data: &DataForType<T, N>, f: F<T>)
+where
+    T: traits::Float + Serialize,
+    ImplFormatter<T>: Formatter,
+{
+    let mut out = Vec::new();
+    crate::measure_with(data, |vec| {
+        crate::fastest_trial(|| {
+            out.clear();
+            let formatter = ImplFormatter { f };
+            let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+            vec.serialize(&mut serializer).unwrap();
+            hint::black_box(&out);
+        })
+    });
+}
@@ -0,0 +1,136 @@
+use crate::data::DataForType;
+use crate::{F, traits};
+use std::hint;
+use std::time::{Duration, Instant};
+
+const BUCKETS: usize = 12;
+const BAR_WIDTH: usize = 50;
+
+// Times every value of each group individually, and prints the median as
+// well as the tail of the distribution of each precision group, followed by
+// a histogram over all groups.
+pub fn measure<T, const N: usize>(data: &DataForType<T, N>, f: F<T>, clock: &Clock)
+where
+    T: traits::Float,
+{
+    if data.groups().next().is_none() {
+        return;
+    }
+    println!("  {} (latency, ns: p50 p90 p99 max)", T::NAME);
+    let mut all = Vec::new();
+    for (prec, vec) in data.groups() {
+        // Untimed pass to fault in the tables and train the branch predictor
+        // like the other modes do.
+        for &value in vec {
+            f(value, &mut |repr| {
+                hint::black_box(repr);
+            });
+        }
+        let mut ticks = Vec::with_capacity(vec.len());
+        for &value in vec {
+            let begin = clock.now();
+            f(hint::black_box(value), &mut |repr| {
+                hint::black_box(repr);
+            });
+            let end = clock.now();
+            // The counter can go backwards if the thread moves between cores.
+            ticks.push(end.saturating_sub(begin).saturating_sub(clock.overhead));
+        }
+        ticks.sort_unstable();
+        let percentile = |p: usize| clock.to_ns(ticks[(ticks.len() - 1) * p / 100]);
+        println!(
+            "    ({prec}, {:.1}) {:.1} {:.1} {:.1}",
+            percentile(50),
+            percentile(90),
+            percentile(99),
+            percentile(100),
+        );
+        all.extend(ticks);
+    }
+    all.sort_unstable();
+    histogram(&all, clock);
+}
+
+// Prints one row per bucket of equal width up to the 99th percentile, with
+// the slower remainder in the last row.
+fn histogram(ticks: &[u64], clock: &Clock) {
+    let limit = ticks[(ticks.len() - 1) * 99 / 100].max(1);
+    let width = limit.div_ceil(BUCKETS as u64 - 1).max(1);
+    let mut counts = [0usize; BUCKETS];
+    for &t in ticks {
+        counts[usize::try_from(t / width)
+            .unwrap_or(usize::MAX)
+            .min(BUCKETS - 1)] += 1;
+    }
+    let most = counts.iter().copied().max().unwrap();
+    for (i, &count) in counts.iter().enumerate() {
+        let lo = clock.to_ns(i as u64 * width);
+        let label = if i == BUCKETS - 1 {
+            format!(">= {lo:.1}")
+        } else {
+            format!("< {:.1}", clock.to_ns((i as u64 + 1) * width))
+        };
+        println!(
+            "    {label:>10} |{:<BAR_WIDTH$}| {count}",
+            "#".repeat(count * BAR_WIDTH / most),
+        );
+    }
+}
+
+// Reads the time stamp counter on x86_64, which is much cheaper and finer
+// grained than Instant, or else the monotonic clock in nanoseconds.
+pub struct Clock {
+    #[cfg_attr(all(target_arch = "x86_64", not(miri)), expect(dead_code))]
+    start: Instant,
+    ns_per_tick: f64,
+    // Smallest number of ticks between two consecutive reads.
+    overhead: u64,
+}
+
+impl Clock {
+    pub fn calibrate() -> Self {
+        let mut clock = Clock {
+            start: Instant::now(),
+            ns_per_tick: 1.0,
+            overhead: 0,
+        };
+        if cfg!(all(target_arch = "x86_64", not(miri))) {
+            let begin = (Instant::now(), clock.now());
+            while begin.0.elapsed() < Duration::from_millis(50) {}
+            let end = (Instant::now(), clock.now());
+            let ticks = end.1.saturating_sub(begin.1).max(1);
+            clock.ns_per_tick = (end.0 - begin.0).as_nanos() as f64 / ticks as f64;
+        }
+        clock.overhead = (0..1000)
+            .map(|_| {
+                let begin = clock.now();
+                clock.now().saturating_sub(begin)
+            })
+            .min()
+            .unwrap();
+        clock
+    }
+
+    #[cfg_attr(all(target_arch = "x86_64", not(miri)), expect(clippy::unused_self))]
+    fn now(&self) -> u64 {
+        #[cfg(all(target_arch = "x86_64", not(miri)))]
+        {
+            // The fences keep the read from being reordered around the
+            // conversion being timed.
+            unsafe {
+                std::arch::x86_64::_mm_lfence();
+                let tsc = std::arch::x86_64::_rdtsc();
+                std::arch::x86_64::_mm_lfence();
+                tsc
+            }
+        }
+        #[cfg(not(all(target_arch = "x86_64", not(miri))))]
+        {
+            u64::try_from(self.start.elapsed().as_nanos()).unwrap()
+        }
+    }
+
+    fn to_ns(&self, ticks: u64) -> f64 {
+        ticks as f64 * self.ns_per_tick
+    }
+}
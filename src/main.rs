@@ -1,24 +1,41 @@
+#![cfg_attr(feature = "f128", feature(f128))]
 #![allow(
     clippy::cast_precision_loss,
     clippy::float_cmp,
     clippy::items_after_statements,
+    clippy::similar_names,
+    clippy::struct_excessive_bools,
+    clippy::too_many_lines,
     clippy::unreadable_literal
 )]
 
 mod args;
+mod batch;
+mod cold;
 mod data;
+mod dispatch;
+mod environment;
+mod external;
+mod json;
+mod latency;
+mod size;
+mod threads;
 mod traits;
 #[cfg(test)]
 mod verify;
+mod warmup;
 
+use crate::args::Args;
 use crate::args::Type;
 use crate::data::{Data, DataForType};
+use crate::latency::Clock;
+use crate::size::Sizes;
 use anyhow::Result;
 use arrayvec::ArrayString;
-use lexical_core::FormattedSize;
-use std::any;
-use std::fmt::Write as _;
+use half::{bf16, f16};
+use std::fmt::{self, Display, Write as _};
 use std::hint;
+use std::mem;
 use std::time::{Duration, Instant};
 
 const COUNT: usize = if cfg!(miri) { 10 } else { 100_000 };
@@ -27,143 +44,588 @@ const PASSES: usize = if cfg!(miri) { 1 } else { 12 };
 
 type F<T> = fn(T, &mut dyn FnMut(&str));
 
+// Measures one group like measure_once, but with the library's function and
+// the callback statically dispatched. See dispatch.rs.
+type Static<T> = fn(&[T]) -> Duration;
+
+// Formats a slice of values into a byte buffer separated by commas, recording
+// the end offset of each value, and returns the total number of bytes written.
+// The buffer has room for fpfmt::MAX_LEN + 1 bytes per value.
+type Batch = fn(&[f64], &mut [u8], &mut [usize]) -> usize;
+
 #[derive(Copy, Clone)]
 struct Impl {
     name: &'static str,
+    // Version of the crate, or of rustc for core, from build.rs.
+    version: Option<&'static str>,
+    url: Option<&'static str>,
+    notation: Notation,
+    // Color of the library's bars in chart/performance.tex.
+    color: Option<&'static str>,
     f32: Option<F<f32>>,
     f64: Option<F<f64>>,
+    // Like f32 and f64, but using the library's entry point that also accepts
+    // NaN and infinity. None if f32 and f64 accept them already.
+    f32_format: Option<F<f32>>,
+    f64_format: Option<F<f64>>,
+    // Like f32 and f64, for finite half precision and bfloat16 values.
+    f16: Option<F<f16>>,
+    bf16: Option<F<bf16>>,
+    // Finite f128 values, with the f128 feature on nightly.
+    #[cfg(feature = "f128")]
+    f128: Option<F<f128>>,
+    f32_static: Option<Static<f32>>,
+    f64_static: Option<Static<f64>>,
+    batch: Option<Batch>,
+}
+
+impl Impl {
+    // The library's f32 function for inputs that include NaN and infinity.
+    fn format_f32(&self) -> Option<F<f32>> {
+        self.f32_format.or(self.f32)
+    }
+
+    // The library's f64 function for inputs that include NaN and infinity.
+    fn format_f64(&self) -> Option<F<f64>> {
+        self.f64_format.or(self.f64)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Notation {
+    // Scientific notation for very large or small magnitudes, otherwise
+    // decimal notation, whichever is shorter.
+    Shortest,
+    // Always scientific notation, like 1.5e3.
+    Exponential,
+    // Never scientific notation, like 1500.0.
+    Fixed,
+}
+
+impl Display for Notation {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            Notation::Shortest => "shortest",
+            Notation::Exponential => "exponential",
+            Notation::Fixed => "fixed",
+        })
+    }
 }
 
 static IMPLS: &[Impl] = &[
     Impl {
         name: "core[Display]",
-        f32: Some(|value, f| {
-            let mut buffer = ArrayString::<327>::new();
-            write!(buffer, "{value}").unwrap();
-            f(&buffer);
-        }),
-        f64: Some(|value, f| {
-            let mut buffer = ArrayString::<327>::new();
-            write!(buffer, "{value}").unwrap();
-            f(&buffer);
-        }),
+        version: Some(env!("VERSION_RUSTC")),
+        url: Some("https://doc.rust-lang.org/std/fmt/trait.Display.html"),
+        notation: Notation::Fixed,
+        color: Some("1F77B4"),
+        f32: Some(dispatch::dynamic::<f32, dispatch::CoreDisplay>),
+        f64: Some(dispatch::dynamic::<f64, dispatch::CoreDisplay>),
+        f32_format: None,
+        f64_format: None,
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: Some(dispatch::measure_once::<f32, dispatch::CoreDisplay>),
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::CoreDisplay>),
+        batch: None,
     },
     Impl {
         name: "core[LowerExp]",
-        f32: Some(|value, f| {
-            let mut buffer = ArrayString::<24>::new();
-            write!(buffer, "{value:e}").unwrap();
-            f(&buffer);
-        }),
-        f64: Some(|value, f| {
-            let mut buffer = ArrayString::<24>::new();
-            write!(buffer, "{value:e}").unwrap();
-            f(&buffer);
-        }),
+        version: Some(env!("VERSION_RUSTC")),
+        url: Some("https://doc.rust-lang.org/std/fmt/trait.LowerExp.html"),
+        notation: Notation::Exponential,
+        color: Some("FF7F0E"),
+        f32: Some(dispatch::dynamic::<f32, dispatch::CoreLowerExp>),
+        f64: Some(dispatch::dynamic::<f64, dispatch::CoreLowerExp>),
+        f32_format: None,
+        f64_format: None,
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: Some(dispatch::measure_once::<f32, dispatch::CoreLowerExp>),
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::CoreLowerExp>),
+        batch: None,
+    },
+    // Not core's own algorithm, which half does not use: see
+    // lower_exp_search and the README.
+    Impl {
+        name: "core[LowerExp,search]",
+        version: Some(env!("VERSION_RUSTC")),
+        url: Some("https://doc.rust-lang.org/std/fmt/trait.LowerExp.html"),
+        notation: Notation::Exponential,
+        color: Some("FFBB78"),
+        f32: None,
+        f64: None,
+        f32_format: None,
+        f64_format: None,
+        f16: Some(|value, f| lower_exp_search(value, f16::to_f64, f16::from_f32, f)),
+        bf16: Some(|value, f| lower_exp_search(value, bf16::to_f64, bf16::from_f32, f)),
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: None,
+        f64_static: None,
+        batch: None,
     },
     Impl {
         name: "dtoa",
-        f32: Some(|value, f| f(dtoa::Buffer::new().format_finite(value))),
-        f64: Some(|value, f| f(dtoa::Buffer::new().format_finite(value))),
+        version: Some(env!("VERSION_DTOA")),
+        url: Some("https://github.com/dtolnay/dtoa"),
+        notation: Notation::Shortest,
+        color: Some("2CA02C"),
+        f32: Some(dispatch::dynamic::<f32, dispatch::Dtoa>),
+        f64: Some(dispatch::dynamic::<f64, dispatch::Dtoa>),
+        f32_format: Some(|value, f| f(dtoa::Buffer::new().format(value))),
+        f64_format: Some(|value, f| f(dtoa::Buffer::new().format(value))),
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: Some(dispatch::measure_once::<f32, dispatch::Dtoa>),
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::Dtoa>),
+        batch: None,
     },
     Impl {
         name: "lexical",
-        f32: Some(|value, f| {
-            let mut buffer = [0u8; f32::FORMATTED_SIZE_DECIMAL];
-            let bytes = lexical_core::write(value, &mut buffer);
-            f(unsafe { str::from_utf8_unchecked(bytes) });
-        }),
-        f64: Some(|value, f| {
-            let mut buffer = [0u8; f64::FORMATTED_SIZE_DECIMAL];
-            let bytes = lexical_core::write(value, &mut buffer);
-            f(unsafe { str::from_utf8_unchecked(bytes) });
-        }),
+        version: Some(env!("VERSION_LEXICAL_CORE")),
+        url: Some("https://github.com/Alexhuszagh/rust-lexical"),
+        notation: Notation::Shortest,
+        color: Some("D62728"),
+        f32: Some(dispatch::dynamic::<f32, dispatch::Lexical>),
+        f64: Some(dispatch::dynamic::<f64, dispatch::Lexical>),
+        f32_format: None,
+        f64_format: None,
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: Some(dispatch::measure_once::<f32, dispatch::Lexical>),
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::Lexical>),
+        batch: None,
     },
     Impl {
         name: "ryu",
-        f32: Some(|value, f| f(ryu::Buffer::new().format_finite(value))),
-        f64: Some(|value, f| f(ryu::Buffer::new().format_finite(value))),
+        version: Some(env!("VERSION_RYU")),
+        url: Some("https://github.com/dtolnay/ryu"),
+        notation: Notation::Shortest,
+        color: Some("9467BD"),
+        f32: Some(dispatch::dynamic::<f32, dispatch::Ryu>),
+        f64: Some(dispatch::dynamic::<f64, dispatch::Ryu>),
+        f32_format: Some(|value, f| f(ryu::Buffer::new().format(value))),
+        f64_format: Some(|value, f| f(ryu::Buffer::new().format(value))),
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: Some(dispatch::measure_once::<f32, dispatch::Ryu>),
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::Ryu>),
+        batch: None,
     },
     Impl {
         name: "dragonbox",
+        version: Some(env!("VERSION_DRAGONBOX")),
+        url: Some("https://github.com/dtolnay/dragonbox"),
+        notation: Notation::Shortest,
+        color: Some("7F7F7F"),
         f32: None,
-        f64: Some(|value, f| f(dragonbox::Buffer::new().format_finite(value))),
+        f64: Some(dispatch::dynamic::<f64, dispatch::Dragonbox>),
+        f32_format: None,
+        f64_format: Some(|value, f| f(dragonbox::Buffer::new().format(value))),
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: None,
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::Dragonbox>),
+        batch: None,
     },
     #[cfg(not(miri))] // https://github.com/andrepd/teju-jagua-rs/issues/1
     Impl {
         name: "teju",
-        f32: Some(|value, f| f(teju::Buffer::new().format_finite(value))),
-        f64: Some(|value, f| f(teju::Buffer::new().format_finite(value))),
+        version: Some(env!("VERSION_TEJU")),
+        url: Some("https://github.com/andrepd/teju-jagua-rs"),
+        notation: Notation::Shortest,
+        color: Some("9C564B"),
+        f32: Some(dispatch::dynamic::<f32, dispatch::Teju>),
+        f64: Some(dispatch::dynamic::<f64, dispatch::Teju>),
+        f32_format: Some(|value, f| f(teju::Buffer::new().format(value))),
+        f64_format: Some(|value, f| f(teju::Buffer::new().format(value))),
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: Some(dispatch::measure_once::<f32, dispatch::Teju>),
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::Teju>),
+        batch: None,
     },
     Impl {
         name: "zmij",
-        f32: Some(|value, f| f(zmij::Buffer::new().format_finite(value))),
-        f64: Some(|value, f| f(zmij::Buffer::new().format_finite(value))),
+        version: Some(env!("VERSION_ZMIJ")),
+        url: Some("https://github.com/dtolnay/zmij"),
+        notation: Notation::Shortest,
+        color: Some("E377C2"),
+        f32: Some(dispatch::dynamic::<f32, dispatch::Zmij>),
+        f64: Some(dispatch::dynamic::<f64, dispatch::Zmij>),
+        f32_format: Some(|value, f| f(zmij::Buffer::new().format(value))),
+        f64_format: Some(|value, f| f(zmij::Buffer::new().format(value))),
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: Some(dispatch::measure_once::<f32, dispatch::Zmij>),
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::Zmij>),
+        batch: None,
     },
     Impl {
         name: "fpfmt",
+        version: Some(env!("VERSION_FPFMT")),
+        url: Some("https://github.com/rsc/fpfmt"),
+        notation: Notation::Exponential,
+        color: Some("BCBD22"),
+        f32: None,
+        f64: Some(dispatch::dynamic::<f64, dispatch::Fpfmt>),
+        f32_format: None,
+        f64_format: Some(|value, f| f(fpfmt::Buffer::new().format(value))),
+        f16: Some(|value, f| f(fpfmt::Buffer::new().format_finite_f16(value.to_bits()))),
+        bf16: Some(|value, f| f(fpfmt::Buffer::new().format_finite_bf16(value.to_bits()))),
+        #[cfg(feature = "f128")]
+        f128: Some(|value, f| {
+            f(fpfmt::binary128::Buffer::new().format_finite(value.to_bits()));
+        }),
+        f32_static: None,
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::Fpfmt>),
+        batch: Some(|values, out, offsets| fpfmt::format_finite_slice(values, b',', out, offsets)),
+    },
+    Impl {
+        name: "fpfmt[small]",
+        version: Some(env!("VERSION_FPFMT")),
+        url: Some("https://github.com/rsc/fpfmt"),
+        notation: Notation::Exponential,
+        color: Some("17BECF"),
         f32: None,
-        f64: Some(|value, f| f(fpfmt::Buffer::new().format_finite(value))),
+        f64: Some(dispatch::dynamic::<f64, dispatch::FpfmtSmall>),
+        f32_format: None,
+        f64_format: Some(|value, f| f(fpfmt::small::Buffer::new().format(value))),
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: None,
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::FpfmtSmall>),
+        batch: None,
+    },
+    Impl {
+        name: "fpfmt[Display]",
+        version: Some(env!("VERSION_FPFMT")),
+        url: Some("https://github.com/rsc/fpfmt"),
+        notation: Notation::Exponential,
+        color: Some("AEC7E8"),
+        f32: None,
+        f64: Some(dispatch::dynamic::<f64, dispatch::FpfmtDisplay>),
+        f32_format: None,
+        f64_format: None,
+        f16: None,
+        bf16: None,
+        #[cfg(feature = "f128")]
+        f128: None,
+        f32_static: None,
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::FpfmtDisplay>),
+        batch: None,
     },
     Impl {
         name: "null",
-        f32: Some(|_value, f| f("")),
-        f64: Some(|_value, f| f("")),
+        version: None,
+        url: None,
+        notation: Notation::Shortest,
+        color: None,
+        f32: Some(dispatch::dynamic::<f32, dispatch::Null>),
+        f64: Some(dispatch::dynamic::<f64, dispatch::Null>),
+        f32_format: None,
+        f64_format: None,
+        f16: Some(|_value, f| f("")),
+        bf16: Some(|_value, f| f("")),
+        #[cfg(feature = "f128")]
+        f128: Some(|_value, f| f("")),
+        f32_static: Some(dispatch::measure_once::<f32, dispatch::Null>),
+        f64_static: Some(dispatch::measure_once::<f64, dispatch::Null>),
+        batch: None,
     },
 ];
 
-fn measure<T, const N: usize>(data: &DataForType<T, N>, f: F<T>)
+// Shortest formatting of a 16-bit value using core's {:.prec$e} of the value
+// widened to f64, since half's LowerExp formats it widened to f32 instead.
+// At each precision, from 0 up, it tries the correctly rounded decimal and
+// then its two neighbors, returning the first that round trips. The rounded
+// one alone is not always shortest: 2^-6 rounds to 1.562e-2, which does not
+// round trip, while 1.563e-2 does.
+fn lower_exp_search<T>(
+    value: T,
+    to_f64: fn(T) -> f64,
+    from_f32: fn(f32) -> T,
+    f: &mut dyn FnMut(&str),
+) where
+    T: traits::Float<Bits = u16>,
+{
+    let x = to_f64(value);
+    let roundtrips = |repr: &str| from_f32(repr.parse().unwrap()).to_bits() == value.to_bits();
+    let mut buffer = ArrayString::<24>::new();
+    for prec in 0.. {
+        buffer.clear();
+        write!(buffer, "{x:.prec$e}").unwrap();
+        if roundtrips(&buffer) {
+            break;
+        }
+        let rounded: f64 = buffer.parse().unwrap();
+        let exp: i32 = buffer.split_once('e').unwrap().1.parse().unwrap();
+        let step = 10f64.powi(exp - i32::try_from(prec).unwrap());
+        for neighbor in [rounded - step, rounded + step] {
+            buffer.clear();
+            write!(buffer, "{neighbor:.prec$e}").unwrap();
+            if roundtrips(&buffer) {
+                f(&buffer);
+                return;
+            }
+        }
+    }
+    f(&buffer);
+}
+
+fn measure<T, const N: usize>(data: &DataForType<T, N>, f: F<T>, null: Option<&[f64]>)
 where
     T: traits::Float,
 {
-    println!("  {}", any::type_name::<T>());
+    let Some(null) = null else {
+        measure_with(data, |vec| measure_once(vec, f));
+        return;
+    };
+    if data.groups().next().is_none() {
+        return;
+    }
+    println!("  {} (net of null)", T::NAME);
+    let mut null = null.iter();
+    time_groups(
+        data,
+        |vec| measure_once(vec, f),
+        |prec, ns| {
+            let net = ns - null.next().unwrap();
+            println!("    ({prec}, {net:.2}) raw {ns:.2}");
+        },
+    );
+}
+
+// Prints the time per value of each precision group, as measured by
+// measure_once over all values of the group.
+fn measure_with<T, const N: usize>(
+    data: &DataForType<T, N>,
+    measure_once: impl FnMut(&[T]) -> Duration,
+) where
+    T: traits::Float,
+{
+    if data.groups().next().is_none() {
+        return;
+    }
+    println!("  {}", T::NAME);
+    time_groups(data, measure_once, |prec, ns| {
+        println!("    ({prec}, {ns:.2})");
+    });
+}
+
+// Reports the nanoseconds per value of each precision group in turn.
+fn time_groups<T, const N: usize>(
+    data: &DataForType<T, N>,
+    mut measure_once: impl FnMut(&[T]) -> Duration,
+    mut report: impl FnMut(usize, f64),
+) {
     let baseline = if data.unpredictable {
-        measure_once(&data.mixed, f)
+        measure_once(&data.mixed)
     } else {
         Duration::ZERO
     };
-    for (i, vec) in data.by_precision.iter().enumerate() {
-        let duration = measure_once(vec, f).saturating_sub(baseline);
-        println!(
-            "    ({}, {:.2})",
-            i + 1,
+    for (prec, vec) in data.groups() {
+        let duration = measure_once(vec).saturating_sub(baseline);
+        report(
+            prec,
             duration.as_secs_f64() * 1e9 / (PASSES * data.count) as f64,
         );
     }
 }
 
+// Time per value of the null implementation in each precision group, which
+// is the cost of the harness itself.
+fn measure_null<T, const N: usize>(data: &DataForType<T, N>, f: F<T>) -> Vec<f64>
+where
+    T: traits::Float,
+{
+    let mut null = Vec::new();
+    time_groups(data, |vec| measure_once(vec, f), |_prec, ns| null.push(ns));
+    null
+}
+
 fn measure_once<T>(data: &[T], f: F<T>) -> Duration
 where
     T: traits::Float,
 {
+    fastest_trial(|| {
+        for &value in data {
+            f(value, &mut |repr| {
+                hint::black_box(repr);
+            });
+        }
+    })
+}
+
+fn fastest_trial(mut pass: impl FnMut()) -> Duration {
     let mut duration = Duration::MAX;
     for _trial in 0..TRIALS {
         let begin = Instant::now();
         for _pass in 0..PASSES {
-            for &value in data {
-                f(value, &mut |repr| {
-                    hint::black_box(repr);
-                });
-            }
+            pass();
         }
         duration = Ord::min(duration, begin.elapsed());
     }
     duration
 }
 
+// Measures f using whichever of the modes that every type supports was
+// selected, or else the default measurement.
+fn measure_in_mode<T, const N: usize>(
+    args: &Args,
+    data: &DataForType<T, N>,
+    f: F<T>,
+    measure_static: Option<Static<T>>,
+    null: Option<&[f64]>,
+    clock: Option<&Clock>,
+) where
+    T: traits::Float + Send + Sync,
+{
+    if let Some(clock) = clock {
+        latency::measure(data, f, clock);
+    } else if let Some(batch) = args.cold {
+        cold::measure(data, f, batch);
+    } else if let Some(n) = args.threads {
+        threads::measure(data, f, n);
+    } else if args.static_dispatch
+        && let Some(measure_static) = measure_static
+    {
+        dispatch::measure(data, f, measure_static);
+    } else {
+        measure(data, f, null);
+    }
+}
+
 fn main() -> Result<()> {
-    let args = args::parse()?;
-    let data = Data::random(COUNT, args.unpredictable);
+    let mut args = args::parse()?;
+    environment::print();
+    let sizes = if args.size {
+        Some(Sizes::measure()?)
+    } else {
+        None
+    };
+    let data = Data::random(COUNT, &args);
+    let clock = args.latency.then(Clock::calibrate);
+    if let Some(duration) = args.spin_up {
+        warmup::spin_up(duration, args.verbose);
+    }
+    let null = IMPLS.iter().find(|imp| imp.name == "null").unwrap();
+    let (null_f32, null_f64) = if args.nonfinite {
+        (null.format_f32(), null.format_f64())
+    } else {
+        (null.f32, null.f64)
+    };
+    let null_f32 = args
+        .subtract_null
+        .then(|| measure_null(&data.f32, null_f32.unwrap()));
+    let null_f64 = args
+        .subtract_null
+        .then(|| measure_null(&data.f64, null_f64.unwrap()));
+    let null_f16 = args
+        .subtract_null
+        .then(|| measure_null(&data.f16, null.f16.unwrap()));
+    let null_bf16 = args
+        .subtract_null
+        .then(|| measure_null(&data.bf16, null.bf16.unwrap()));
+    #[cfg(feature = "f128")]
+    let null_f128 = args
+        .subtract_null
+        .then(|| measure_null(&data.f128, null.f128.unwrap()));
     let mut prev_name = None;
 
-    for (name, ty) in args.benchmark {
-        if prev_name != Some(name) {
-            println!("\n{name}");
-            prev_name = Some(name);
+    for (imp, ty) in mem::take(&mut args.benchmark) {
+        // Only f32 and f64 support --json and --static.
+        let extra = !matches!(ty, Type::F32(_) | Type::F64(_));
+        if args.batch && !matches!(ty, Type::F64(_)) || extra && (args.json || args.static_dispatch)
+        {
+            continue;
+        }
+        if prev_name != Some(imp.name) {
+            print!("\n{}", imp.name);
+            if let Some(version) = imp.version {
+                print!(" {version}");
+            }
+            // Helpers run by --external have no binary in size/src/bin.
+            let external = matches!(ty, Type::ExternalF32(_) | Type::ExternalF64(_));
+            if let Some(sizes) = &sizes
+                && !external
+            {
+                let footprint = sizes.get(imp.name)?;
+                print!(
+                    " (.text {} B, .rodata {} B)",
+                    footprint.text, footprint.rodata,
+                );
+            }
+            println!();
+            print!("  # notation: {}", imp.notation);
+            if let Some(url) = imp.url {
+                print!(", url: {url}");
+            }
+            if let Some(color) = imp.color {
+                print!(", color: #{color}");
+            }
+            println!();
+            prev_name = Some(imp.name);
         }
+        if let Some(warmup) = args.warmup {
+            match ty {
+                Type::F32(f) => warmup::run(&data.f32, f, warmup, args.verbose),
+                Type::F64(f) => warmup::run(&data.f64, f, warmup, args.verbose),
+                Type::F16(f) => warmup::run(&data.f16, f, warmup, args.verbose),
+                Type::BF16(f) => warmup::run(&data.bf16, f, warmup, args.verbose),
+                #[cfg(feature = "f128")]
+                Type::F128(f) => warmup::run(&data.f128, f, warmup, args.verbose),
+                // The helper process is responsible for its own warmup.
+                Type::ExternalF32(_) | Type::ExternalF64(_) => {}
+            }
+        }
+        let clock = clock.as_ref();
         match ty {
-            Type::F32(f) => measure(&data.f32, f),
-            Type::F64(f) => measure(&data.f64, f),
+            Type::F64(f) if args.batch => batch::measure(&data.f64, f, imp.batch),
+            Type::F32(f) if args.json => json::measure(&data.f32, f),
+            Type::F64(f) if args.json => json::measure(&data.f64, f),
+            Type::F32(f) => {
+                let null = null_f32.as_deref();
+                measure_in_mode(&args, &data.f32, f, imp.f32_static, null, clock);
+            }
+            Type::F64(f) => {
+                let null = null_f64.as_deref();
+                measure_in_mode(&args, &data.f64, f, imp.f64_static, null, clock);
+            }
+            Type::F16(f) => {
+                let null = null_f16.as_deref();
+                measure_in_mode(&args, &data.f16, f, None, null, clock);
+            }
+            Type::BF16(f) => {
+                let null = null_bf16.as_deref();
+                measure_in_mode(&args, &data.bf16, f, None, null, clock);
+            }
+            #[cfg(feature = "f128")]
+            Type::F128(f) => {
+                let null = null_f128.as_deref();
+                measure_in_mode(&args, &data.f128, f, None, null, clock);
+            }
+            Type::ExternalF32(external) => external.measure(&data.f32)?,
+            Type::ExternalF64(external) => external.measure(&data.f64)?,
         }
     }
 
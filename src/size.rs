@@ -0,0 +1,91 @@
+use anyhow::{Context as _, Result, bail};
+use object::{Object as _, ObjectSection as _, SectionKind};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// Bytes of code and of read-only data that a library adds to a binary that
+// formats one f64 with it, or one f16 if it has no f64 support, compared to
+// a binary that formats nothing.
+#[derive(Copy, Clone, Default)]
+pub struct Footprint {
+    pub text: u64,
+    pub rodata: u64,
+}
+
+pub struct Sizes {
+    bins: Vec<(&'static str, Footprint)>,
+}
+
+impl Sizes {
+    // Builds the minimal binaries in size/src/bin, one per Impl, all of which
+    // must have one.
+    pub fn measure() -> Result<Self> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let target_dir = root.join("target").join("size");
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let status = Command::new(cargo)
+            .args(["build", "--release", "--bins", "--quiet", "--manifest-path"])
+            .arg(root.join("size").join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .status()?;
+        if !status.success() {
+            bail!("failed to build size binaries");
+        }
+
+        let release = target_dir.join("release");
+        let read =
+            |bin: &str| footprint(&release.join(format!("{bin}{}", env::consts::EXE_SUFFIX)));
+        let null = read("null")?;
+        let src = root.join("size").join("src").join("bin");
+        let mut bins = Vec::new();
+        for imp in crate::IMPLS {
+            let bin = bin_name(imp.name);
+            if !src.join(format!("{bin}.rs")).exists() {
+                bail!("no size/src/bin/{bin}.rs for {}", imp.name);
+            }
+            let footprint = read(&bin)?;
+            bins.push((
+                imp.name,
+                Footprint {
+                    text: footprint.text.saturating_sub(null.text),
+                    rodata: footprint.rodata.saturating_sub(null.rodata),
+                },
+            ));
+        }
+        Ok(Sizes { bins })
+    }
+
+    pub fn get(&self, name: &str) -> Result<Footprint> {
+        match self.bins.iter().find(|(bin, _footprint)| *bin == name) {
+            Some((_bin, footprint)) => Ok(*footprint),
+            None => bail!("no size/src/bin/{}.rs for {name}", bin_name(name)),
+        }
+    }
+}
+
+// "core[Display]" is built as size/src/bin/core-display.rs, and
+// "core[LowerExp,search]" as core-lowerexp-search.rs.
+fn bin_name(name: &str) -> String {
+    name.to_lowercase()
+        .replace(['[', ','], "-")
+        .replace(']', "")
+}
+
+fn footprint(path: &Path) -> Result<Footprint> {
+    let data = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let file = object::File::parse(&*data)?;
+    let mut footprint = Footprint::default();
+    for section in file.sections() {
+        match section.kind() {
+            SectionKind::Text => footprint.text += section.size(),
+            SectionKind::ReadOnlyData
+            | SectionKind::ReadOnlyDataWithRel
+            | SectionKind::ReadOnlyString => footprint.rodata += section.size(),
+            _ => {}
+        }
+    }
+    Ok(footprint)
+}
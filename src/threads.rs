@@ -0,0 +1,72 @@
+use crate::data::DataForType;
+use crate::{F, PASSES, TRIALS, traits};
+use std::hint;
+use std::sync::Barrier;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Measures the same group in every thread at once, each thread over its own
+// copy of the values, and prints per-thread time per value alongside the
+// aggregate throughput of all threads.
+pub fn measure<T, const N: usize>(data: &DataForType<T, N>, f: F<T>, threads: usize)
+where
+    T: traits::Float + Send + Sync,
+{
+    if data.groups().next().is_none() {
+        return;
+    }
+    let plural = if threads == 1 { "" } else { "s" };
+    println!("  {} ({threads} thread{plural})", T::NAME);
+    let baseline = if data.unpredictable {
+        measure_concurrently(&data.mixed, f, threads)
+    } else {
+        Duration::ZERO
+    };
+    for (prec, vec) in data.groups() {
+        let duration = measure_concurrently(vec, f, threads).saturating_sub(baseline);
+        let ns = duration.as_secs_f64() * 1e9 / (PASSES * data.count) as f64;
+        println!("    ({prec}, {ns:.2}) {:.1}M/s", threads as f64 * 1e3 / ns);
+    }
+}
+
+// Returns the fastest trial, timing each trial by its slowest thread. Every
+// trial starts in all threads at once, so that each thread formats while the
+// others do.
+fn measure_concurrently<T>(vec: &[T], f: F<T>, threads: usize) -> Duration
+where
+    T: traits::Float + Send + Sync,
+{
+    let barrier = Barrier::new(threads);
+    let trials: Vec<Vec<Duration>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    let copy = vec.to_vec();
+                    (0..TRIALS)
+                        .map(|_trial| {
+                            barrier.wait();
+                            let begin = Instant::now();
+                            for _pass in 0..PASSES {
+                                for &value in &copy {
+                                    f(value, &mut |repr| {
+                                        hint::black_box(repr);
+                                    });
+                                }
+                            }
+                            begin.elapsed()
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    (0..TRIALS)
+        .map(|trial| trials.iter().map(|thread| thread[trial]).max().unwrap())
+        .min()
+        .unwrap()
+}
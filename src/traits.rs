@@ -1,28 +1,305 @@
-use std::fmt::{Debug, LowerExp};
-use std::str::FromStr;
+use half::{bf16, f16};
+use std::fmt::LowerHex;
 
-pub trait Float: Copy + LowerExp + FromStr<Err: Debug> {
-    type Bits;
+pub trait Float: Copy {
+    type Bits: LowerHex;
+    // How the type is labeled in the results.
+    const NAME: &str;
+    const NAN: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
     fn from_bits(bits: Self::Bits) -> Self;
+    fn to_bits(self) -> Self::Bits;
     fn is_finite(self) -> bool;
+
+    // Rounds self to prec+1 significant decimal digits.
+    fn round_to_precision(self, prec: usize) -> Self;
 }
 
 impl Float for f32 {
+    const NAME: &str = "f32";
     type Bits = u32;
+    const NAN: Self = f32::NAN;
+    const INFINITY: Self = f32::INFINITY;
+    const NEG_INFINITY: Self = f32::NEG_INFINITY;
     fn from_bits(bits: u32) -> Self {
         f32::from_bits(bits)
     }
+    fn to_bits(self) -> u32 {
+        f32::to_bits(self)
+    }
     fn is_finite(self) -> bool {
         f32::is_finite(self)
     }
+    fn round_to_precision(self, prec: usize) -> Self {
+        format!("{self:.prec$e}").parse().unwrap()
+    }
 }
 
 impl Float for f64 {
+    const NAME: &str = "f64";
     type Bits = u64;
+    const NAN: Self = f64::NAN;
+    const INFINITY: Self = f64::INFINITY;
+    const NEG_INFINITY: Self = f64::NEG_INFINITY;
     fn from_bits(bits: u64) -> Self {
         f64::from_bits(bits)
     }
+    fn to_bits(self) -> u64 {
+        f64::to_bits(self)
+    }
     fn is_finite(self) -> bool {
         f64::is_finite(self)
     }
+    fn round_to_precision(self, prec: usize) -> Self {
+        format!("{self:.prec$e}").parse().unwrap()
+    }
+}
+
+impl Float for f16 {
+    const NAME: &str = "f16";
+    type Bits = u16;
+    const NAN: Self = f16::NAN;
+    const INFINITY: Self = f16::INFINITY;
+    const NEG_INFINITY: Self = f16::NEG_INFINITY;
+    fn from_bits(bits: u16) -> Self {
+        f16::from_bits(bits)
+    }
+    fn to_bits(self) -> u16 {
+        f16::to_bits(self)
+    }
+    fn is_finite(self) -> bool {
+        f16::is_finite(self)
+    }
+    // half's LowerExp ignores the precision, and converting the decimal back
+    // through f32 or f64 would round twice.
+    fn round_to_precision(self, prec: usize) -> Self {
+        let repr = format!("{:.prec$e}", self.to_f64());
+        f16::from_bits(u16::try_from(exact::parse(&repr, &exact::BINARY16)).unwrap())
+    }
+}
+
+impl Float for bf16 {
+    const NAME: &str = "bf16";
+    type Bits = u16;
+    const NAN: Self = bf16::NAN;
+    const INFINITY: Self = bf16::INFINITY;
+    const NEG_INFINITY: Self = bf16::NEG_INFINITY;
+    fn from_bits(bits: u16) -> Self {
+        bf16::from_bits(bits)
+    }
+    fn to_bits(self) -> u16 {
+        bf16::to_bits(self)
+    }
+    fn is_finite(self) -> bool {
+        bf16::is_finite(self)
+    }
+    // half's LowerExp ignores the precision, and converting the decimal back
+    // through f32 or f64 would round twice.
+    fn round_to_precision(self, prec: usize) -> Self {
+        let repr = format!("{:.prec$e}", self.to_f64());
+        bf16::from_bits(u16::try_from(exact::parse(&repr, &exact::BFLOAT16)).unwrap())
+    }
+}
+
+#[cfg(feature = "f128")]
+impl Float for f128 {
+    const NAME: &str = "f128";
+    type Bits = u128;
+    const NAN: Self = f128::NAN;
+    const INFINITY: Self = f128::INFINITY;
+    const NEG_INFINITY: Self = f128::NEG_INFINITY;
+    fn from_bits(bits: u128) -> Self {
+        f128::from_bits(bits)
+    }
+    fn to_bits(self) -> u128 {
+        f128::to_bits(self)
+    }
+    fn is_finite(self) -> bool {
+        f128::is_finite(self)
+    }
+    // core can neither format nor parse f128, so this rounds the exact value
+    // to prec+1 digits and converts that decimal back exactly, to nearest.
+    fn round_to_precision(self, prec: usize) -> Self {
+        let bits = self.to_bits();
+        let sign = bits & (1 << 127);
+        if bits == sign {
+            return self;
+        }
+        let (d, p) = exact::round_decimal(bits ^ sign, u32::try_from(prec).unwrap() + 1);
+        f128::from_bits(sign | exact::nearest(d, p, &exact::BINARY128))
+    }
+}
+
+// Exact conversions between binary floating-point values, passed as bits,
+// and decimals d * 10**p.
+pub mod exact {
+    use num_bigint::BigUint;
+    use std::sync::LazyLock;
+
+    // A binary format with the given number of significant bits, whose
+    // smallest subnormal is 2**min_exp, and whose largest finite value and
+    // sign have the given bits.
+    pub struct Format {
+        precision: i32,
+        min_exp: i32,
+        max_bits: u128,
+        sign: u128,
+    }
+
+    pub const BINARY16: Format = Format {
+        precision: 11,
+        min_exp: -24,
+        max_bits: 0x7bff,
+        sign: 1 << 15,
+    };
+
+    pub const BFLOAT16: Format = Format {
+        precision: 8,
+        min_exp: -133,
+        max_bits: 0x7f7f,
+        sign: 1 << 15,
+    };
+
+    #[cfg(feature = "f128")]
+    pub const BINARY128: Format = Format {
+        precision: 113,
+        min_exp: -16494,
+        max_bits: 0x7ffe_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+        sign: 1 << 127,
+    };
+
+    // POW10[k] is 10**k, for every exponent needed between the smallest
+    // binary128 subnormal with 36 digits and the largest finite value.
+    // Computing them as needed would take most of the time of generating the
+    // f128 data.
+    static POW10: LazyLock<Vec<BigUint>> = LazyLock::new(|| {
+        let mut pow10 = vec![BigUint::from(1u32)];
+        for k in 1..5040 {
+            pow10.push(&pow10[k - 1] * 10u32);
+        }
+        pow10
+    });
+
+    // 10**p as num / den.
+    fn pow10(p: i32) -> (&'static BigUint, &'static BigUint) {
+        let pow = &POW10[usize::try_from(p.unsigned_abs()).unwrap()];
+        if p >= 0 {
+            (pow, &POW10[0])
+        } else {
+            (&POW10[0], pow)
+        }
+    }
+
+    // Returns ⌊num / den * 2**e⌋, which must fit in a u128, and whether it
+    // was inexact.
+    fn div_floor(num: &BigUint, den: &BigUint, e: i32) -> (u128, bool) {
+        // Dividing by a power of two is a shift.
+        if den.bits() == 1 {
+            let shift = e.unsigned_abs();
+            if e >= 0 {
+                return (u128::try_from(num << shift).unwrap(), false);
+            }
+            let sticky = num
+                .trailing_zeros()
+                .is_some_and(|zeros| zeros < u64::from(shift));
+            return (u128::try_from(num >> shift).unwrap(), sticky);
+        }
+        let (num, den) = if e >= 0 {
+            (num << e.unsigned_abs(), den.clone())
+        } else {
+            (num.clone(), den << e.unsigned_abs())
+        };
+        // Estimate the quotient from the leading bits, which is faster than
+        // dividing in full, then correct it with exact products.
+        let t = den.bits().saturating_sub(192);
+        let mut q = (&num >> t) / (&den >> t);
+        let mut product = &q * &den;
+        while product > num {
+            q -= 1u32;
+            product -= &den;
+        }
+        let mut r = num - product;
+        while r >= den {
+            q += 1u32;
+            r -= &den;
+        }
+        (u128::try_from(q).unwrap(), r.bits() != 0)
+    }
+
+    // Rounds q / unit to nearest, ties to even, where sticky says whether q
+    // was itself truncated.
+    fn round(q: u128, unit: u128, sticky: bool) -> u128 {
+        let (q, r, half) = (q / unit, q % unit, unit / 2);
+        q + u128::from(r > half || r == half && (sticky || q % 2 == 1))
+    }
+
+    // Rounds the positive finite binary128 value with the given bits to
+    // n <= 36 significant digits, ties to even, returning d, p with
+    // 10**(n-1) <= d < 10**n.
+    #[cfg(feature = "f128")]
+    pub fn round_decimal(bits: u128, n: u32) -> (u128, i32) {
+        let biased = i32::try_from(bits >> 112).unwrap();
+        let mantissa = bits & ((1 << 112) - 1);
+        let (mantissa, exp) = if biased == 0 {
+            (mantissa, -16494)
+        } else {
+            (mantissa | (1 << 112), biased - 16495)
+        };
+        // The shortest decimal gives the exponent of the leading digit, or
+        // one more if it rounded up to a power of ten. Truncate to n+1 or n+2
+        // digits, then round off the extra ones.
+        let (shortest, shortest_p) = fpfmt::binary128::to_decimal(bits);
+        let mut p =
+            shortest_p + i32::try_from(shortest.ilog10()).unwrap() - i32::try_from(n + 1).unwrap();
+        let (den, num) = pow10(p);
+        let (truncated, sticky) = div_floor(&(num * mantissa), den, exp);
+        let extra = truncated.ilog10() + 1 - n;
+        p += i32::try_from(extra).unwrap();
+        let d = round(truncated, 10u128.pow(extra), sticky);
+        if d == 10u128.pow(n) {
+            (d / 10, p + 1)
+        } else {
+            (d, p)
+        }
+    }
+
+    // Returns the bits of the value in the given format nearest to
+    // d * 10**p, ties to even, or of +inf if that is beyond the largest
+    // finite value.
+    pub fn nearest(d: u128, p: i32, format: &Format) -> u128 {
+        let (num, den) = pow10(p);
+        let num = num * d;
+        // Truncate to 3 or 4 more bits than the precision, or to the
+        // subnormal exponent with 3 more bits, then round off the extra bits
+        // to leave q * 2**e with q < 2**precision.
+        let log2 = i32::try_from(num.bits()).unwrap() - i32::try_from(den.bits()).unwrap();
+        let e0 = (log2 - format.precision - 3).max(format.min_exp - 3);
+        let (q, sticky) = div_floor(&num, den, -e0);
+        let bits = i32::try_from(128 - q.leading_zeros()).unwrap();
+        let e = (e0 + bits - format.precision).max(format.min_exp);
+        let q = round(q, 1 << (e - e0), sticky);
+        // The implicit bit of a normal q adds one to the exponent field, and a
+        // q that rounded up to the next power of two carries into it.
+        let shift = format.precision - 1;
+        let bits = (u128::try_from(e - format.min_exp).unwrap() << shift) + q;
+        bits.min(format.max_bits + 1)
+    }
+
+    // Returns the bits of the value in the given format nearest to the
+    // decimal repr, like "-1.25e-3", ties to even.
+    pub fn parse(repr: &str, format: &Format) -> u128 {
+        let (sign, repr) = match repr.strip_prefix('-') {
+            Some(repr) => (format.sign, repr),
+            None => (0, repr),
+        };
+        let (mantissa, exp) = repr.split_once('e').unwrap();
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let d: u128 = format!("{int}{frac}").parse().unwrap();
+        let p = exp.parse::<i32>().unwrap() - i32::try_from(frac.len()).unwrap();
+        if d == 0 {
+            return sign;
+        }
+        sign | nearest(d, p, format)
+    }
 }
@@ -3,6 +3,9 @@ use rand::{Rng as _, SeedableRng as _};
 
 const VERIFY_RANDOM_COUNT: usize = if cfg!(miri) { 40 } else { 100_000 };
 
+#[cfg(feature = "f128")]
+const VERIFY_F128_COUNT: usize = if cfg!(miri) { 10 } else { 10_000 };
+
 fn verify_value(value: f64, f: crate::F<f64>) -> usize {
     let mut len = 0;
 
@@ -69,3 +72,215 @@ fn verify_all() {
         }
     }
 }
+
+#[test]
+fn verify_nonfinite() {
+    for imp in crate::IMPLS {
+        if imp.name != "null"
+            && let Some(f) = imp.format_f64()
+        {
+            for (value, expected) in [
+                (f64::NAN, "NaN"),
+                (f64::INFINITY, "inf"),
+                (f64::NEG_INFINITY, "-inf"),
+            ] {
+                f(value, &mut |actual| {
+                    assert_eq!(actual, expected, "{}", imp.name);
+                });
+            }
+        }
+    }
+}
+
+// The 16-bit formats are small enough to check every finite value. Each
+// output must lie in the value's rounding interval, found from the exact
+// midpoints between neighboring values rather than by converting the output
+// back with half, and have as few significant digits as fpfmt's, which
+// fpfmt/tests/half.rs checks against exact arithmetic.
+fn verify_half<T: crate::traits::Float<Bits = u16>>(
+    f: crate::F<T>,
+    to_f64: fn(T) -> f64,
+    shortest: fn(&mut fpfmt::Buffer, u16) -> &str,
+    name: &str,
+) {
+    let step = if cfg!(miri) { 997 } else { 1 };
+    let mut buffer = fpfmt::Buffer::new();
+    for bits in (0..=u16::MAX).step_by(step) {
+        let value = T::from_bits(bits);
+        if !value.is_finite() {
+            continue;
+        }
+        let x = to_f64(value);
+        let abs = bits & 0x7fff;
+        let (lo, hi) = if abs == 0 {
+            (0.0, 0.0)
+        } else {
+            let a = x.abs();
+            let prev = to_f64(T::from_bits(abs - 1));
+            let next = match to_f64(T::from_bits(abs + 1)) {
+                next if next.is_finite() => next,
+                _ => a + (a - prev),
+            };
+            (f64::midpoint(prev, a), f64::midpoint(a, next))
+        };
+        let digits = significant_digits(shortest(&mut buffer, bits));
+        f(value, &mut |actual| {
+            let parsed: f64 = actual.parse().unwrap();
+            let v = parsed.abs();
+            let inside = lo < v && v < hi || (v == lo || v == hi) && abs.is_multiple_of(2);
+            assert!(
+                inside && parsed.is_sign_negative() == x.is_sign_negative(),
+                "{name}: {x:e} ({bits:#06x}) -> {actual}",
+            );
+            if abs != 0 {
+                assert_eq!(
+                    significant_digits(actual),
+                    digits,
+                    "{name}: {x:e} ({bits:#06x}) -> {actual} is not shortest",
+                );
+            }
+        });
+    }
+}
+
+// Number of significant digits in a decimal like "-1.250e-3".
+fn significant_digits(repr: &str) -> usize {
+    let mantissa = repr
+        .split_once('e')
+        .map_or(repr, |(mantissa, _exp)| mantissa);
+    let digits = mantissa.replace(['-', '.'], "");
+    digits.trim_matches('0').len()
+}
+
+#[test]
+fn verify_f16_bf16() {
+    for imp in crate::IMPLS {
+        if imp.name == "null" {
+            continue;
+        }
+        if let Some(f) = imp.f16 {
+            verify_half(
+                f,
+                half::f16::to_f64,
+                fpfmt::Buffer::format_finite_f16,
+                imp.name,
+            );
+        }
+        if let Some(f) = imp.bf16 {
+            verify_half(
+                f,
+                half::bf16::to_f64,
+                fpfmt::Buffer::format_finite_bf16,
+                imp.name,
+            );
+        }
+    }
+}
+
+// core cannot parse f128, so parse_f128 rounds the exact value of repr to
+// the nearest binary128, ties to even, and returns its bits.
+#[cfg(feature = "f128")]
+fn parse_f128(repr: &str) -> u128 {
+    use crate::traits::exact;
+
+    exact::parse(repr, &exact::BINARY128)
+}
+
+// The f128 data is rounded with exact arithmetic, which must agree with core
+// on the values f64 can represent, and keep every digit of the values at the
+// highest precision.
+#[cfg(feature = "f128")]
+#[test]
+fn f128_round_to_precision() {
+    use crate::traits::{Float as _, exact};
+
+    let mut rng = SmallRng::seed_from_u64(1);
+    for _ in 0..VERIFY_F128_COUNT {
+        let value = f64::from_bits(rng.next_u64()).abs();
+        if !value.is_finite() || value == 0.0 {
+            continue;
+        }
+        let prec = usize::try_from(rng.next_u32() % 17).unwrap();
+        let repr = format!("{value:.prec$e}");
+        let (mantissa, exp) = repr.split_once('e').unwrap();
+        let d: u128 = mantissa.replace('.', "").parse().unwrap();
+        let p = exp.parse::<i32>().unwrap() - i32::try_from(prec).unwrap();
+        let bits = (value as f128).to_bits();
+        let digits = u32::try_from(prec).unwrap() + 1;
+        assert_eq!(exact::round_decimal(bits, digits), (d, p), "{repr}");
+
+        let bits = u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64());
+        let bits = bits & !(1 << 127);
+        if f128::from_bits(bits).is_finite() && bits != 0 {
+            let (d, p) = exact::round_decimal(bits, 36);
+            assert_eq!(exact::nearest(d, p, &exact::BINARY128), bits, "{d}e{p}");
+        }
+    }
+    for bits in [1, (1 << 112) - 1, 1 << 112, f128::MAX.to_bits()] {
+        let (d, p) = exact::round_decimal(bits, 36);
+        assert_eq!(exact::nearest(d, p, &exact::BINARY128), bits, "{d}e{p}");
+    }
+    assert_eq!(2.5f128.round_to_precision(0), 2.0);
+    assert_eq!(3.5f128.round_to_precision(0), 4.0);
+    assert_eq!(f128::MAX.round_to_precision(1), f128::INFINITY);
+    assert_eq!(
+        f128::MAX.round_to_precision(0).to_bits(),
+        parse_f128("1e4932")
+    );
+    let min = f128::from_bits(1);
+    assert_eq!((-min).round_to_precision(0), -min);
+}
+
+#[cfg(feature = "f128")]
+#[test]
+fn verify_f128() {
+    let mut r = SmallRng::seed_from_u64(1);
+    for imp in crate::IMPLS {
+        if imp.name == "null" {
+            continue;
+        }
+        let Some(f) = imp.f128 else {
+            continue;
+        };
+        print!("Verifying {:20} ... ", imp.name);
+        let boundaries = [1, (1 << 112) - 1, 1 << 112, 0x3fff << 112, 0x7ffe << 112];
+        let random = std::iter::repeat_with(|| {
+            f128::from_bits(u128::from(r.next_u64()) << 64 | u128::from(r.next_u64()))
+        });
+        let values = boundaries.into_iter().map(f128::from_bits).chain(random);
+        for value in values
+            .filter(|value| value.is_finite())
+            .take(VERIFY_F128_COUNT)
+        {
+            f(value, &mut |actual| {
+                let roundtrip = parse_f128(actual);
+                assert_eq!(roundtrip, value.to_bits(), "{}: {actual}", imp.name);
+            });
+        }
+        println!("OK");
+    }
+}
+
+// The f16 and bf16 data is rounded to the nearest value of the decimal with
+// exact arithmetic. bf16::from_f64 of the decimal, which rounds twice, gets
+// this one wrong: 1.39e-13 is just above the midpoint 1.389999...e-13
+// between 0x2a1c and 0x2a1d.
+#[test]
+fn half_round_to_precision() {
+    use crate::traits::Float as _;
+    use half::{bf16, f16};
+
+    let value = bf16::from_bits(0x2a1c);
+    assert_eq!(format!("{:.2e}", value.to_f64()), "1.39e-13");
+    assert_eq!(value.round_to_precision(2).to_bits(), 0x2a1d);
+    assert_eq!(
+        bf16::from_bits(0xaa1c).round_to_precision(2).to_bits(),
+        0xaa1d
+    );
+    assert_eq!(bf16::MAX.round_to_precision(1), bf16::INFINITY);
+    assert_eq!(f16::MAX.round_to_precision(1), f16::INFINITY);
+    assert_eq!(f16::MAX.round_to_precision(2).to_f64(), 65504.0);
+    let min = f16::from_bits(1);
+    assert_eq!(min.round_to_precision(0).to_bits(), 1);
+    assert_eq!((-f16::ZERO).round_to_precision(0).to_bits(), 0x8000);
+}
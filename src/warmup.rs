@@ -0,0 +1,97 @@
+use crate::data::DataForType;
+use crate::{F, traits};
+use anyhow::{Result, bail};
+use std::hint;
+use std::time::{Duration, Instant};
+
+#[derive(Copy, Clone)]
+pub enum Warmup {
+    Passes(usize),
+    Time(Duration),
+}
+
+impl Warmup {
+    // Parses a number of passes like "3", or a duration like "500ms" or "2s".
+    pub fn parse(s: &str) -> Result<Self> {
+        if s.ends_with('s') {
+            parse_duration(s).map(Warmup::Time)
+        } else if let Ok(passes) = s.parse() {
+            Ok(Warmup::Passes(passes))
+        } else {
+            bail!("expected a number of passes or a duration like 500ms: {s}");
+        }
+    }
+}
+
+// Parses a duration like "500ms" or "2s".
+pub fn parse_duration(s: &str) -> Result<Duration> {
+    let duration = if let Some(ms) = s.strip_suffix("ms") {
+        ms.parse().ok().map(Duration::from_millis)
+    } else if let Some(secs) = s.strip_suffix('s') {
+        secs.parse()
+            .ok()
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+    } else {
+        None
+    };
+    let Some(duration) = duration else {
+        bail!("expected a duration like 2s or 500ms: {s}");
+    };
+    Ok(duration)
+}
+
+// Formats the groups in turn, one group per pass, until the warmup is over.
+// The timings are discarded unless verbose.
+pub fn run<T, const N: usize>(data: &DataForType<T, N>, f: F<T>, warmup: Warmup, verbose: bool)
+where
+    T: traits::Float,
+{
+    let groups: Vec<&[T]> = data.groups().map(|(_prec, vec)| vec).collect();
+    if groups.is_empty() {
+        return;
+    }
+    let begin = Instant::now();
+    let mut passes = 0;
+    let mut values = 0;
+    while match warmup {
+        Warmup::Passes(n) => passes < n,
+        Warmup::Time(duration) => begin.elapsed() < duration,
+    } {
+        let vec = groups[passes % groups.len()];
+        for &value in vec {
+            f(value, &mut |repr| {
+                hint::black_box(repr);
+            });
+        }
+        passes += 1;
+        values += vec.len();
+    }
+    if verbose {
+        let elapsed = begin.elapsed();
+        println!(
+            "  # {} warmup: {passes} passes in {:.0} ms, {:.2} ns/value",
+            T::NAME,
+            elapsed.as_secs_f64() * 1e3,
+            elapsed.as_secs_f64() * 1e9 / values.max(1) as f64,
+        );
+    }
+}
+
+// Keeps the core busy so that it has reached its sustained clock frequency
+// before the first library is measured.
+pub fn spin_up(duration: Duration, verbose: bool) {
+    let begin = Instant::now();
+    let mut iterations = 0u64;
+    while begin.elapsed() < duration {
+        for _ in 0..1000 {
+            iterations = hint::black_box(iterations + 1);
+        }
+    }
+    if verbose {
+        println!(
+            "# spin-up: {:.0} ms, {:.2} ns/iteration",
+            begin.elapsed().as_secs_f64() * 1e3,
+            begin.elapsed().as_secs_f64() * 1e9 / iterations as f64,
+        );
+    }
+}